@@ -0,0 +1,201 @@
+//! Generates the module list of the `aoc-all` binary, which compiles every solution in `src/bin/{year}`
+//! into a single executable, the example tests declared in `data/{year}/examples/{day}.toml`, and the
+//! number of days of each event configured in `aoc.toml`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[path = "src/template/config.rs"]
+#[allow(dead_code)]
+mod config;
+#[path = "src/template/examples.rs"]
+#[allow(dead_code)]
+mod examples;
+
+use config::{Config, CONFIG_PATH};
+use examples::Examples;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let config_path = Path::new(&manifest_dir).join(CONFIG_PATH);
+    let config = Config::read(&config_path).unwrap_or_else(|e| panic!("{e}"));
+    let bin_dir = Path::new(&manifest_dir).join(&config.paths.bin);
+
+    println!("cargo:rerun-if-changed={}", config_path.display());
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    fs::write(
+        Path::new(&out_dir).join("event_days.rs"),
+        event_days_module(&config),
+    )
+    .unwrap();
+
+    let mut puzzles: Vec<Puzzle> = numbered_entries(&bin_dir, None)
+        .into_iter()
+        .flat_map(|(year, year_dir)| {
+            println!("cargo:rerun-if-changed={}", year_dir.display());
+            numbered_entries(&year_dir, Some("rs")).into_iter().map(
+                move |(day, path)| Puzzle {
+                    year: year.clone(),
+                    day,
+                    path: path.display().to_string(),
+                },
+            )
+        })
+        .collect();
+
+    puzzles.sort_by(|a, b| (&a.year, &a.day).cmp(&(&b.year, &b.day)));
+    check_bins(&manifest_dir, &config, &puzzles);
+
+    fs::write(
+        Path::new(&out_dir).join("solutions.rs"),
+        solutions_module(&puzzles),
+    )
+    .unwrap();
+
+    for puzzle in &puzzles {
+        let year = puzzle.year.parse().unwrap();
+        let examples_dir = Path::new(&manifest_dir)
+            .join(config.paths.folder(year, "examples"));
+        println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+        let manifest = examples_dir.join(format!("{}.toml", puzzle.day));
+        let tests = match fs::read_to_string(&manifest) {
+            Ok(s) => match Examples::parse(&s) {
+                Ok(examples) => example_tests(&examples),
+                Err(e) => {
+                    panic!("invalid examples in {}: {e}", manifest.display())
+                }
+            },
+            Err(_) => String::new(),
+        };
+
+        let tests_dir =
+            Path::new(&out_dir).join("example_tests").join(&puzzle.year);
+        fs::create_dir_all(&tests_dir).unwrap();
+
+        // named after the unpadded day number, as passed to `example_tests!`.
+        let day_number: u8 = puzzle.day.parse().unwrap();
+        fs::write(tests_dir.join(format!("{day_number}.rs")), tests).unwrap();
+    }
+}
+
+/// A solution in `src/bin/{year}/{day}.rs`.
+struct Puzzle {
+    year: String,
+    day: String,
+    path: String,
+}
+
+/// Warns about solutions without a `[[bin]]` entry in `Cargo.toml`, which `cargo solve` cannot run.
+/// The entries are written by `scaffold`, but not moved when `paths.bin` changes.
+fn check_bins(manifest_dir: &str, config: &Config, puzzles: &[Puzzle]) {
+    let manifest_path = Path::new(manifest_dir).join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let manifest = fs::read_to_string(manifest_path).unwrap_or_default();
+
+    for Puzzle { year, day, .. } in puzzles {
+        // written the same way as by `scaffold`.
+        let path = format!("{}/{year}/{day}.rs", config.paths.bin.display());
+        let entry = format!("path = \"{path}\"");
+
+        if !manifest.lines().any(|line| line.trim() == entry) {
+            println!(
+                "cargo:warning={path} has no `[[bin]]` entry in Cargo.toml, add one or update its path."
+            );
+        }
+    }
+}
+
+/// Lists the entries of `dir` named by digits only, e.g. `2025` or `01.rs`, with their paths.
+/// Only files with the given extension are listed if `extension` is set, only directories otherwise.
+fn numbered_entries(
+    dir: &Path,
+    extension: Option<&str>,
+) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let is_kind = match extension {
+                Some(extension) => path.extension()? == extension,
+                None => path.is_dir(),
+            };
+            let is_numbered =
+                !stem.is_empty() && stem.chars().all(|c| c.is_ascii_digit());
+            (is_kind && is_numbered).then_some((stem, path))
+        })
+        .collect()
+}
+
+fn event_days_module(config: &Config) -> String {
+    let mut out = String::from("const EVENT_DAYS: &[(u16, u8)] = &[\n");
+
+    for (year, days) in config.event_days() {
+        out.push_str(&format!("    ({year}, {days}),\n"));
+    }
+
+    out.push_str("];\n");
+    out
+}
+
+fn solutions_module(puzzles: &[Puzzle]) -> String {
+    let mut out = String::new();
+
+    for Puzzle { year, day, path } in puzzles {
+        out.push_str(&format!(
+            "#[path = {path:?}]\n#[allow(dead_code)]\nmod y{year}_day_{day};\n"
+        ));
+    }
+
+    out.push_str(
+        "\nconst SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[\n",
+    );
+
+    for Puzzle { year, day, .. } in puzzles {
+        out.push_str(&format!("    y{year}_day_{day}::SOLUTION,\n"));
+    }
+
+    out.push_str("];\n");
+    out
+}
+
+fn example_tests(examples: &Examples) -> String {
+    let mut out = String::new();
+
+    for (i, example) in examples.examples.iter().enumerate() {
+        for (part, name) in [(1, "part_one"), (2, "part_two")] {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+
+            let ignore = match &example.ignore {
+                Some(reason) => format!("#[ignore = {reason:?}]\n"),
+                None => String::new(),
+            };
+
+            out.push_str(&format!(
+                r#"
+#[test]
+{ignore}fn {name}_example_{number}() {{
+    let input = advent_of_code::template::read_example_file(PUZZLE.year, {file:?});
+    let context = advent_of_code::template::context::Context::example(PUZZLE, {i});
+    let result = solve_example({part}, &input, &context);
+    let expected = advent_of_code::Answer::from({expected:?}).recognized();
+    assert_eq!(result.map(advent_of_code::Answer::recognized), Some(expected), "example {{}}", {file:?});
+}}
+"#,
+                number = i + 1,
+                file = example.file,
+            ));
+        }
+    }
+
+    out
+}
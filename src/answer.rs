@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{ocr, Grid};
+
+/// The answer of a solution part. Parts may return any type that converts into an answer, which is
+/// what gets printed, submitted and compared with recorded and expected answers.
+///
+/// Answers are normalized when they are created, so that e.g. the string `"42"` equals the integer
+/// `42` (but `"0042"` stays text) and ASCII art equals itself regardless of trailing whitespace.
+/// ASCII art drawing letters is submitted and compared as those letters, see [`Answer::recognized`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Several lines of ASCII art, e.g. letters drawn by lit pixels.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Whether the answer spans several lines.
+    #[must_use]
+    pub fn is_grid(&self) -> bool {
+        matches!(self, Self::Grid(_))
+    }
+
+    /// The letters drawn by ASCII art, if it draws known letters, see [`ocr::recognize`].
+    #[must_use]
+    pub fn letters(&self) -> Option<String> {
+        let Self::Grid(rows) = self else {
+            return None;
+        };
+
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let data = rows
+            .iter()
+            .flat_map(|row| row.chars().pad_using(width, |_| '.'))
+            .collect();
+
+        ocr::recognize(&Grid::new(data, width)).ok()
+    }
+
+    /// The answer with ASCII art replaced by the letters it draws, which is what gets submitted.
+    #[must_use]
+    pub fn recognized(self) -> Self {
+        self.letters().map_or(self, Self::Text)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        let s = s.trim_end();
+
+        // only canonical integers, text like `0042` or `+5` is kept as is.
+        if let Ok(n) = s.trim().parse::<i128>() {
+            if n.to_string() == s.trim() {
+                return Self::Integer(n);
+            }
+        }
+
+        if s.contains('\n') {
+            return Self::Grid(
+                s.trim_start_matches('\n')
+                    .lines()
+                    .map(|row| row.trim_end().to_string())
+                    .collect(),
+            );
+        }
+
+        Self::Text(s.trim().to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Self::from(c.to_string())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n)
+            .map_or_else(|_| Self::Text(n.to_string()), Self::Integer)
+    }
+}
+
+macro_rules! integer_answers {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+integer_answers!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::from(n as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+/// Lit cells are drawn as `#`, unlit ones as `.`.
+impl From<Grid<bool>> for Answer {
+    fn from(grid: Grid<bool>) -> Self {
+        Self::Grid(
+            grid.rows()
+                .map(|row| {
+                    row.iter().map(|&lit| if lit { '#' } else { '.' }).collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Cells are drawn as the characters they were parsed from, see [`Grid::parse_lines`].
+impl From<Grid<u8>> for Answer {
+    fn from(grid: Grid<u8>) -> Self {
+        Self::Grid(
+            grid.rows()
+                .map(|row| row.iter().map(|&c| char::from(c)).collect())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Answer;
+    use crate::Grid;
+
+    #[test]
+    fn converts_integers() {
+        assert_eq!(Answer::from(42_u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-3_i64), Answer::Integer(-3));
+        assert_eq!(Answer::from(7_usize), Answer::Integer(7));
+        assert_eq!(
+            Answer::from(u128::MAX),
+            Answer::Text(u128::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn normalizes_strings() {
+        assert_eq!(Answer::from("42\n"), Answer::from(42_u64));
+        assert_eq!(Answer::from(" abc "), Answer::Text("abc".into()));
+        assert_eq!(Answer::from("1,2,3"), Answer::Text("1,2,3".into()));
+        assert_eq!(Answer::from("0042"), Answer::Text("0042".into()));
+        assert_eq!(Answer::from("+5"), Answer::Text("+5".into()));
+        assert_eq!(Answer::from("-0"), Answer::Text("-0".into()));
+        assert_eq!(Answer::from("-7"), Answer::Integer(-7));
+        assert_eq!(
+            Answer::from("\n#. \n.#\n"),
+            Answer::Grid(vec!["#.".into(), ".#".into()])
+        );
+    }
+
+    #[test]
+    fn draws_grids() {
+        let grid = Grid::new(vec![true, false, false, true], 2);
+        let answer = Answer::from(grid);
+
+        assert!(answer.is_grid());
+        assert_eq!(answer.to_string(), "#.\n.#");
+        assert_eq!(Answer::from(answer.to_string()), answer);
+        assert_eq!(
+            Answer::from(Grid::<u8>::parse_lines("ab\ncd")).to_string(),
+            "ab\ncd"
+        );
+    }
+
+    #[test]
+    fn recognizes_letters() {
+        let art = Answer::from(
+            "\n#..#.###\n#..#..#.\n####..#.\n#..#..#.\n#..#..#.\n#..#.###\n",
+        );
+
+        assert_eq!(art.letters(), Some("HI".into()));
+        assert_eq!(art.recognized(), Answer::Text("HI".into()));
+
+        let blocks = Answer::from(
+            "█  █ ███\n█  █  █\n████  █\n█  █  █\n█  █  █\n█  █ ███",
+        );
+        assert_eq!(blocks.letters(), Some("HI".into()));
+
+        assert_eq!(Answer::from("#.\n.#").recognized(), Answer::from("#.\n.#"));
+        assert_eq!(Answer::from(42_u8).recognized(), Answer::Integer(42));
+    }
+}
@@ -0,0 +1,11 @@
+//! Runs every implemented day in a single process.
+//!
+//! Accepts the same `--time` and `--format` arguments as the solution binaries, plus
+//! `--parallel` to run the days concurrently and `--year <year|all>` to pick the years to run.
+use advent_of_code::template::commands::all;
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    all::handle_in_process(SOLUTIONS);
+}
@@ -3,7 +3,7 @@ use nom::{
     combinator::map, sequence::preceded, IResult, Parser,
 };
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2025, 1);
 
 fn parse_rotation(line: &str) -> i64 {
     let result: IResult<&'_ str, _> = alt((
@@ -61,21 +61,4 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some(count + (pos == 0) as i64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
+advent_of_code::example_tests!(2025, 1);
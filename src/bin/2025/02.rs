@@ -6,7 +6,7 @@ use nom::{
     IResult, Parser,
 };
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
 #[derive(Debug, Clone, Copy)]
 struct Range {
@@ -113,21 +113,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
-}
+advent_of_code::example_tests!(2025, 2);
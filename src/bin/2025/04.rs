@@ -1,7 +1,7 @@
 use advent_of_code::Grid;
 use itertools::Itertools;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid: Grid<u8> = Grid::parse_lines(input);
@@ -80,21 +80,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(total)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
-    }
-}
+advent_of_code::example_tests!(2025, 4);
@@ -1,4 +1,4 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5);
 
 use std::{collections::BTreeMap, ops::RangeInclusive};
 
@@ -85,21 +85,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(starts.into_iter().map(|((s, _), e)| e - s + 1).sum::<u64>())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-}
+advent_of_code::example_tests!(2025, 5);
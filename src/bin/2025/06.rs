@@ -9,7 +9,7 @@ use nom::{
     IResult, Parser,
 };
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2025, 6);
 
 fn parse_number_from_line(input: &str) -> IResult<&'_ str, Vec<u64>> {
     many1(ws(u64)).parse(input)
@@ -105,21 +105,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(result)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
-    }
-}
+advent_of_code::example_tests!(2025, 6);
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use advent_of_code::{Compass, Grid};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2025, 7);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid: Grid<u8> = Grid::parse_lines(input);
@@ -82,21 +82,4 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(paths_down_from(&grid, start, &mut memo))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-}
+advent_of_code::example_tests!(2025, 7);
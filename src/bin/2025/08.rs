@@ -3,6 +3,7 @@ use std::{
     collections::{HashMap, HashSet, VecDeque},
 };
 
+use advent_of_code::template::context::Context;
 use i_key_sort::sort::one_key::OneKeySort;
 use itertools::Itertools;
 use nom::{
@@ -10,13 +11,13 @@ use nom::{
     multi::separated_list1, sequence::delimited, IResult, Parser,
 };
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8, parse);
 
-#[cfg(test)]
-const CONNECTIONS: usize = 10;
-
-#[cfg(not(test))]
-const CONNECTIONS: usize = 1000;
+/// The junction boxes and the distances (squared) between all of them, sorted.
+pub struct Playground {
+    boxes: Vec<[u64; 3]>,
+    distances: Vec<(u64, (usize, usize))>,
+}
 
 fn parse_boxes(input: &str) -> IResult<&'_ str, Vec<[u64; 3]>> {
     separated_list1(
@@ -95,27 +96,32 @@ fn calc_segments(
     segments
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn parse(input: &str) -> Playground {
     let boxes = parse_boxes(input).unwrap().1;
     let distances = calc_distances(&boxes);
 
-    let mut segments = calc_segments(&boxes, &distances, CONNECTIONS);
+    Playground { boxes, distances }
+}
+
+pub fn part_one(
+    Playground { boxes, distances }: &Playground,
+    context: &Context,
+) -> Option<u64> {
+    let mut segments =
+        calc_segments(boxes, distances, context.param("connections"));
     segments.sort_unstable();
 
     Some(segments.iter().rev().take(3).product())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let boxes = parse_boxes(input).unwrap().1;
-    let distances = calc_distances(&boxes);
-
+pub fn part_two(Playground { boxes, distances }: &Playground) -> Option<u64> {
     let possible_k = (0..distances.len()).collect_vec();
 
     // Binary search on k to find the first edge that creates a totally
     // connected graph
     let min_k = possible_k
         .binary_search_by(|k| {
-            let len = calc_segments(&boxes, &distances, *k).len();
+            let len = calc_segments(boxes, distances, *k).len();
 
             if len > 1 {
                 Ordering::Less
@@ -130,21 +136,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(boxes[from][0] * boxes[to][0])
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
-}
+advent_of_code::example_tests!(2025, 8);
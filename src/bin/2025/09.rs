@@ -4,7 +4,7 @@ use nom::{
     sequence::preceded, IResult, Parser,
 };
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2025, 9);
 
 fn parse_tiles(input: &str) -> IResult<&'_ str, Vec<(u64, u64)>> {
     separated_list1(tag("\n"), (u64, preceded(tag(","), u64))).parse(input)
@@ -119,21 +119,4 @@ pub fn part_two(input: &str) -> Option<u64> {
         .next()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
-    }
-}
+advent_of_code::example_tests!(2025, 9);
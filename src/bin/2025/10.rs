@@ -15,12 +15,12 @@ use nom::{
     IResult, Parser,
 };
 use num_rational::Rational64;
-use rayon::iter::IntoParallelIterator;
+use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::ParallelIterator;
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10, parse);
 
 #[derive(Debug)]
-struct Setup {
+pub struct Setup {
     button_state: Vec<bool>,
     buttons: Vec<Vec<u64>>,
     joltages: Vec<u16>,
@@ -53,6 +53,10 @@ fn parse_setups(input: &str) -> IResult<&'_ str, Vec<Setup>> {
     .parse(input)
 }
 
+pub fn parse(input: &str) -> Vec<Setup> {
+    parse_setups(input).unwrap().1
+}
+
 #[derive(Debug, Clone)]
 struct Sum(Rational64, HashMap<u8, Rational64>);
 
@@ -223,7 +227,7 @@ impl Setup {
             .collect()
     }
 
-    fn solve_part_2(self) -> usize {
+    fn solve_part_2(&self) -> usize {
         let mut soe = SoE::default();
         self.as_equations()
             .into_iter()
@@ -272,33 +276,12 @@ impl Setup {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let setups = parse_setups(input).unwrap().1;
-
-    Some(setups.into_iter().map(|s| s.solve_part_1()).sum())
+pub fn part_one(setups: &[Setup]) -> Option<usize> {
+    Some(setups.iter().map(Setup::solve_part_1).sum())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let setups = parse_setups(input).unwrap().1;
-
-    Some(setups.into_par_iter().map(|s| s.solve_part_2()).sum())
+pub fn part_two(setups: &[Setup]) -> Option<usize> {
+    Some(setups.par_iter().map(Setup::solve_part_2).sum())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
-    }
-}
+advent_of_code::example_tests!(2025, 10);
@@ -10,9 +10,12 @@ use nom::{
     IResult, Parser,
 };
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2025, 11, parse_graph);
 
-fn parse_graph(input: &str) -> (HashMap<&'_ str, usize>, Vec<Vec<usize>>) {
+/// The index of each node by name and the edges of each node.
+type Graph<'a> = (HashMap<&'a str, usize>, Vec<Vec<usize>>);
+
+fn parse_graph(input: &str) -> Graph<'_> {
     let parsed: IResult<&'_ str, _> = separated_list1(
         tag("\n"),
         (
@@ -86,38 +89,20 @@ fn all_paths(
     )
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (nodes, edges) = parse_graph(input);
-    Some(all_paths("you", "out", &nodes, &edges))
+pub fn part_one((nodes, edges): &Graph) -> Option<usize> {
+    Some(all_paths("you", "out", nodes, edges))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (nodes, edges) = parse_graph(input);
-
-    debug_assert_eq!(all_paths("dac", "fft", &nodes, &edges), 0);
-    debug_assert_eq!(all_paths("out", "dac", &nodes, &edges), 0);
-    debug_assert_eq!(all_paths("out", "fft", &nodes, &edges), 0);
+pub fn part_two((nodes, edges): &Graph) -> Option<usize> {
+    debug_assert_eq!(all_paths("dac", "fft", nodes, edges), 0);
+    debug_assert_eq!(all_paths("out", "dac", nodes, edges), 0);
+    debug_assert_eq!(all_paths("out", "fft", nodes, edges), 0);
 
     Some(
-        all_paths("svr", "fft", &nodes, &edges)
-            * all_paths("fft", "dac", &nodes, &edges)
-            * all_paths("dac", "out", &nodes, &edges),
+        all_paths("svr", "fft", nodes, edges)
+            * all_paths("fft", "dac", nodes, edges)
+            * all_paths("dac", "out", nodes, edges),
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        // Example input doesn't work for this one
-    }
-}
+advent_of_code::example_tests!(2025, 11);
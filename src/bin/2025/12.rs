@@ -9,7 +9,7 @@ use nom::{
     IResult, Parser,
 };
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2025, 12);
 
 #[derive(Debug)]
 struct Present {
@@ -160,21 +160,17 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
+advent_of_code::example_tests!(2025, 12);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result =
-            part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-
+    // examples can only declare answers, part two has none.
     #[test]
     fn test_part_two() {
         let result =
-            part_two(&advent_of_code::template::read_file("examples", DAY));
+            part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
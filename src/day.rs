@@ -2,6 +2,8 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
 /// # Display
@@ -57,6 +59,26 @@ impl PartialOrd<u8> for Day {
 
 /* -------------------------------------------------------------------------- */
 
+impl Serialize for Day {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        Self::new(day).ok_or_else(|| de::Error::custom(DayFromStrError))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 impl FromStr for Day {
     type Err = DayFromStrError;
 
@@ -80,20 +102,149 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// Identifies a puzzle by its event year and day.
+///
+/// # Display
+/// This value displays as `{year}-{day}`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId};
+/// let puzzle = PuzzleId::new(2025, Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2025-08")
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] for a day of the event in `year`, see [`days_in_event`].
+    ///
+    /// # Panics
+    /// Panics if the event in `year` has fewer days, use [`PuzzleId::checked`] for days read at runtime.
+    #[must_use]
+    pub const fn new(year: u16, day: Day) -> Self {
+        assert!(
+            day.0 <= days_in_event(year),
+            "the day is not part of the event"
+        );
+        Self { year, day }
+    }
+
+    /// Creates a [`PuzzleId`] if `day` is part of the event in `year`, returns an error otherwise.
+    pub fn checked(year: u16, day: Day) -> Result<Self, DayNotInEventError> {
+        let days = days_in_event(year);
+        if day.0 > days {
+            return Err(DayNotInEventError { year, days });
+        }
+        Ok(Self { year, day })
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        Self::checked(
+            year.parse().map_err(|_| PuzzleIdFromStrError)?,
+            day.parse().map_err(|_| PuzzleIdFromStrError)?,
+        )
+        .map_err(|_| PuzzleIdFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the form `{year}-{day}`")
+    }
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An error which can be returned when a day is not part of the event of a year.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayNotInEventError {
+    pub year: u16,
+    pub days: u8,
+}
+
+impl Error for DayNotInEventError {}
+
+impl Display for DayNotInEventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the event in {} only has {} days", self.year, self.days)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+// the `[days]` table of `aoc.toml`, generated by the build script.
+include!(concat!(env!("OUT_DIR"), "/event_days.rs"));
+
+/// Returns the number of days of the event in `year`.
+/// Events have 25 days unless configured otherwise in the `[days]` table of `aoc.toml`.
+///
+/// ```
+/// # use advent_of_code::days_in_event;
+/// assert_eq!(days_in_event(2024), 25);
+/// ```
+#[must_use]
+pub const fn days_in_event(year: u16) -> u8 {
+    let mut i = 0;
+    while i < EVENT_DAYS.len() {
+        if EVENT_DAYS[i].0 == year {
+            return EVENT_DAYS[i].1;
+        }
+        i += 1;
+    }
+    25
+}
+
+/// An iterator that yields every day of the event in `year`, from the 1st to the last.
+pub fn all_days(year: u16) -> AllDays {
+    AllDays::new(year)
+}
+
+/// An iterator that yields every day of an event, from the 1st to the last.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: u16) -> Self {
+        Self::with_days(days_in_event(year))
+    }
+
+    /// Yields the days of an event with `days` days.
+    pub fn with_days(days: u8) -> Self {
+        Self {
+            current: 1,
+            last: days,
+        }
     }
 }
 
@@ -101,10 +252,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and events have at most 25 days.
         let day = Day(self.current);
         self.current += 1;
 
@@ -115,8 +266,22 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// Pass the year as well to check the day against the length of its event.
 #[macro_export]
 macro_rules! day {
+    ($year:expr, $day:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $crate::days_in_event($year),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, the event in ",
+                $year,
+                " has fewer days"
+            ),
+        );
+        $crate::Day::__new_unchecked($day)
+    }};
     ($day:expr) => {{
         const _ASSERT: () = assert!(
             $day != 0 && $day <= 25,
@@ -132,13 +297,40 @@ macro_rules! day {
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{all_days, Day};
+    use super::{
+        all_days, days_in_event, AllDays, Day, DayNotInEventError, PuzzleId,
+    };
+
+    #[test]
+    fn puzzle_id_round_trip() {
+        let puzzle = PuzzleId::new(2024, Day(3));
+
+        assert_eq!(puzzle.to_string(), "2024-03");
+        assert_eq!("2024-03".parse::<PuzzleId>().unwrap(), puzzle);
+        assert_eq!("2024-3".parse::<PuzzleId>().unwrap(), puzzle);
+        assert!("2024".parse::<PuzzleId>().is_err());
+        assert!("2024-26".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn puzzle_id_within_event() {
+        let days = days_in_event(2025);
+        assert!(PuzzleId::checked(2025, Day(days)).is_ok());
+
+        if days < 25 {
+            assert_eq!(
+                PuzzleId::checked(2025, Day(days + 1)),
+                Err(DayNotInEventError { year: 2025, days })
+            );
+            assert!(format!("2025-{}", days + 1).parse::<PuzzleId>().is_err());
+        }
+    }
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(2024);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +359,12 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_shorter_event() {
+        assert_eq!(AllDays::with_days(12).last(), Some(Day(12)));
+        assert_eq!(AllDays::with_days(12).count(), 12);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
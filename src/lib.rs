@@ -1,8 +1,11 @@
+mod answer;
 mod day;
+pub mod ocr;
 pub mod template;
 
 use std::ops::Range;
 
+pub use answer::Answer;
 pub use day::*;
 
 use itertools::Itertools;
@@ -1,31 +1,134 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_diff, download, read, scaffold, solve, verify,
+};
+use advent_of_code::template::config;
 use args::{parse, AppArguments};
 
 mod args {
-    use std::process;
+    use std::{path::PathBuf, process};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{
+            commands::{
+                all::{AllOptions, Years},
+                solve::SolveOptions,
+            },
+            config,
+            context::InputSource,
+            get_year,
+        },
+        Day, PuzzleId,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            template: Option<String>,
+            answer: Option<String>,
+            download: bool,
+            update: bool,
         },
         Solve {
-            day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            puzzle: PuzzleId,
+            options: SolveOptions,
         },
         All {
+            years: Years,
+            options: AllOptions,
+        },
+        Verify {
+            years: Years,
+            day: Option<Day>,
             release: bool,
-            time: bool,
         },
+        BenchDiff {
+            year: Option<u16>,
+            baseline: Option<String>,
+            threshold: f64,
+        },
+    }
+
+    /// Reads the puzzle from the free day argument and `--year`, which defaults to the configured year.
+    fn puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = args.opt_value_from_str("--year")?.or_else(get_year);
+        let day = args.free_from_str()?;
+
+        let Some(year) = year else {
+            return Err(
+                "No year specified, pass `--year` or set `year` in aoc.toml."
+                    .into(),
+            );
+        };
+
+        Ok(PuzzleId::checked(year, day)?)
+    }
+
+    /// Reads `--year`, defaulting to the configured year or every year if there is none.
+    fn years(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Years, pico_args::Error> {
+        Ok(args
+            .opt_value_from_str("--year")?
+            .or_else(|| get_year().map(Years::One))
+            .unwrap_or(Years::Every))
+    }
+
+    /// Reads `--timeout`, the number of seconds after which a part is given up.
+    fn timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--timeout")? {
+            Some(0) => Err("The timeout must be at least 1 second.".into()),
+            timeout => Ok(timeout),
+        }
+    }
+
+    /// Reads `--part`, the only part to run.
+    fn part(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<u8>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--part")? {
+            Some(part @ 1..=2) => Ok(Some(part)),
+            Some(part) => {
+                Err(format!("There is no part {part}, expecting 1 or 2.")
+                    .into())
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the input `solve` runs on from `--input <path>`, `--example [K]` or `--stdin`.
+    /// Must be read after every other argument, the optional example number is the only free one left.
+    fn input(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let file: Option<PathBuf> = args
+            .opt_value_from_os_str("--input", |s| {
+                Ok::<_, std::convert::Infallible>(PathBuf::from(s))
+            })?;
+        let example = if args.contains("--example") {
+            Some(args.opt_free_from_str::<u32>()?.unwrap_or(1))
+        } else {
+            None
+        };
+        let stdin = args.contains("--stdin");
+
+        match (file, example, stdin) {
+            (None, None, false) => Ok(InputSource::Real),
+            (Some(file), None, false) => Ok(InputSource::File(file)),
+            (None, Some(0), false) => Err("Examples are numbered from 1.".into()),
+            (None, Some(number), false) => Ok(InputSource::Example(number)),
+            (None, None, true) => Ok(InputSource::Stdin),
+            _ => Err("Only one of `--input`, `--example` and `--stdin` can be given.".into()),
+        }
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -33,23 +136,61 @@ mod args {
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+                years: years(&mut args)?,
+                options: AllOptions {
+                    release: args.contains("--release"),
+                    time: args.contains("--time"),
+                    parallel: args.contains("--parallel"),
+                    memory: args.contains("--memory"),
+                    timeout: timeout(&mut args)?,
+                    format: args
+                        .opt_value_from_str("--format")?
+                        .unwrap_or_default(),
+                    part: part(&mut args)?,
+                },
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
+                template: args.opt_value_from_str("--template")?,
+                answer: args.opt_value_from_str("--answer")?,
+                download: args.contains("--download"),
+                update: args.contains("--update"),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
+                options: SolveOptions {
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    wait: args.contains("--wait"),
+                    memory: args.contains("--memory"),
+                    timeout: timeout(&mut args)?,
+                    time: args.contains("--time"),
+                    format: args
+                        .opt_value_from_str("--format")?
+                        .unwrap_or_default(),
+                    watch: args.contains("--watch"),
+                    tests: args.contains("--tests"),
+                    part: part(&mut args)?,
+                    input: input(&mut args)?,
+                },
+            },
+            Some("verify") => AppArguments::Verify {
+                years: years(&mut args)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                day: args.opt_free_from_str()?,
+            },
+            Some("bench-diff") => AppArguments::BenchDiff {
+                year: args.opt_value_from_str("--year")?,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(config::get().bench.regression_threshold),
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -71,22 +212,51 @@ mod args {
 }
 
 fn main() {
+    // load the config up front so an invalid one fails before any work is done.
+    config::get();
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
+            AppArguments::All { years, options } => {
+                all::handle(years, &options)
+            }
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
+                template,
+                answer,
+                download,
+                update,
+            } => {
+                if update {
+                    scaffold::update(puzzle);
+                } else {
+                    scaffold::handle(
+                        puzzle,
+                        template.as_deref(),
+                        answer.as_deref(),
+                        download,
+                    );
+                }
+            }
+            AppArguments::Solve { puzzle, options } => {
+                solve::handle(puzzle, &options)
+            }
+            AppArguments::Verify {
+                years,
                 day,
                 release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+            } => verify::handle(years, day, release),
+            AppArguments::BenchDiff {
+                year,
+                baseline,
+                threshold,
+            } => bench_diff::handle(year, baseline.as_deref(), threshold),
         },
     };
 }
@@ -0,0 +1,233 @@
+//! Reads the capital letters some puzzles draw with lit pixels, in the two fonts Advent of Code uses:
+//! letters 6 pixels high (4 wide, e.g. 2016 day 8 or 2022 day 10) and 10 pixels high (6 wide, e.g.
+//! 2018 day 10). Letters are separated by blank columns, blank rows and columns around them are ignored.
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::Grid;
+
+/// Letters 6 pixels high, as drawn with `#` for lit and `.` for unlit pixels.
+const SMALL_FONT: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters 10 pixels high, as drawn with `#` for lit and `.` for unlit pixels.
+const LARGE_FONT: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// A pixel of a picture to read.
+pub trait Pixel {
+    fn is_lit(&self) -> bool;
+}
+
+impl Pixel for bool {
+    fn is_lit(&self) -> bool {
+        *self
+    }
+}
+
+/// A `#` is lit, as in the inputs and in [`Grid::parse_lines`].
+impl Pixel for u8 {
+    fn is_lit(&self) -> bool {
+        *self == b'#'
+    }
+}
+
+/// A `#` or a block like `█` is lit, as in ASCII art printed by solutions.
+impl Pixel for char {
+    fn is_lit(&self) -> bool {
+        matches!(self, '#' | '█' | '▓' | '■')
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Empty,
+    Height(usize),
+    Unknown(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Empty => write!(f, "no pixels are lit"),
+            Error::Height(height) => {
+                write!(f, "letters are {height} pixels high, expecting 6 or 10")
+            }
+            Error::Unknown(glyph) => write!(f, "unknown letter\n{glyph}"),
+        }
+    }
+}
+
+/// Removes the blank columns around a glyph.
+fn trim(glyph: &str) -> String {
+    let rows = glyph.lines().collect_vec();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let is_blank = |col: usize| {
+        rows.iter()
+            .all(|row| row.as_bytes().get(col) != Some(&b'#'))
+    };
+
+    let Some(start) = (0..width).find(|&col| !is_blank(col)) else {
+        return String::new();
+    };
+    let end = (0..width).rfind(|&col| !is_blank(col)).unwrap();
+
+    rows.iter()
+        .map(|row| format!("{row:.<width$}")[start..=end].to_string())
+        .join("\n")
+}
+
+/// Reads the letters drawn in a picture.
+pub fn recognize<T: Pixel>(grid: &Grid<T>) -> Result<String, Error> {
+    let lit =
+        |col: usize, row: usize| grid.data[grid.to_index(col, row)].is_lit();
+
+    let rows = (0..grid.height)
+        .filter(|&row| (0..grid.width).any(|col| lit(col, row)))
+        .collect_vec();
+    let (Some(&top), Some(&bottom)) = (rows.first(), rows.last()) else {
+        return Err(Error::Empty);
+    };
+
+    let font = match bottom - top + 1 {
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        height => return Err(Error::Height(height)),
+    };
+
+    let is_blank = |col: usize| (top..=bottom).all(|row| !lit(col, row));
+    let mut letters = String::new();
+    let mut col = 0;
+
+    while col < grid.width {
+        if is_blank(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < grid.width && !is_blank(col) {
+            col += 1;
+        }
+
+        let glyph = (top..=bottom)
+            .map(|row| {
+                (start..col)
+                    .map(|col| if lit(col, row) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n");
+
+        match font.iter().find(|(_, known)| trim(known) == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None => return Err(Error::Unknown(glyph)),
+        }
+    }
+
+    Ok(letters)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{recognize, Error, LARGE_FONT, SMALL_FONT};
+    use crate::Grid;
+    use itertools::Itertools;
+
+    /// Draws letters next to each other, separated by `gap` blank columns.
+    fn draw(font: &[(char, &str)], letters: &str, gap: usize) -> Grid<u8> {
+        let glyphs = letters
+            .chars()
+            .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1)
+            .map(|glyph| glyph.lines().collect_vec())
+            .collect_vec();
+        let spacing = ".".repeat(gap);
+
+        let picture = (0..glyphs[0].len())
+            .map(|row| glyphs.iter().map(|glyph| glyph[row]).join(&spacing))
+            .join("\n");
+
+        Grid::parse_lines(&picture)
+    }
+
+    #[test]
+    fn reads_small_letters() {
+        let letters: String =
+            SMALL_FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(recognize(&draw(SMALL_FONT, &letters, 1)), Ok(letters));
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let letters: String =
+            LARGE_FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(recognize(&draw(LARGE_FONT, &letters, 2)), Ok(letters));
+    }
+
+    #[test]
+    fn reads_padded_bool_grids() {
+        let mut grid = draw(SMALL_FONT, "HI", 1).map(|c| c == b'#');
+        grid.data.splice(0..0, vec![false; grid.width]);
+        grid.height += 1;
+
+        assert_eq!(recognize(&grid), Ok("HI".into()));
+    }
+
+    #[test]
+    fn reads_block_char_grids() {
+        let grid =
+            draw(SMALL_FONT, "HI", 1)
+                .map(|c| if c == b'#' { '█' } else { ' ' });
+
+        assert_eq!(recognize(&grid), Ok("HI".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_pictures() {
+        assert_eq!(
+            recognize(&Grid::<u8>::parse_lines("...\n...")),
+            Err(Error::Empty)
+        );
+        assert_eq!(
+            recognize(&Grid::<u8>::parse_lines("#.\n##")),
+            Err(Error::Height(2))
+        );
+        assert!(matches!(
+            recognize(&Grid::<u8>::parse_lines("#\n#\n#\n#\n#\n#")),
+            Err(Error::Unknown(_))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+/// Module that stores accepted answers per day so solutions can be checked for regressions.
+/// Answers live in `data/{year}/answers/{day}.txt` with one `{part}: {answer}` line per part.
+/// Newlines and backslashes in answers are escaped.
+use std::{fs, io, path::Path};
+
+use crate::template::get_data_dir;
+use crate::PuzzleId;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_1 = Some(answer.to_string()),
+            2 => self.part_2 = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn parse(s: &str) -> Self {
+        let mut answers = Self::default();
+
+        s.lines()
+            .filter_map(|line| line.split_once(": "))
+            .filter_map(|(part, answer)| {
+                Some((part.trim().parse().ok()?, answer))
+            })
+            .for_each(|(part, answer)| answers.set(part, &unescape(answer)));
+
+        answers
+    }
+
+    fn serialize(&self) -> String {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .into_iter()
+            .filter_map(|(part, answer)| {
+                Some(format!("{part}: {}\n", escape(answer.as_ref()?)))
+            })
+            .collect()
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    let path = get_data_dir(puzzle.year)
+        .join("answers")
+        .join(format!("{}.txt", puzzle.day));
+    path.display().to_string()
+}
+
+/// Reads the accepted answers of a day. A missing file means no answers are known yet.
+pub fn read(puzzle: PuzzleId) -> io::Result<Answers> {
+    match fs::read_to_string(get_path(puzzle)) {
+        Ok(s) => Ok(Answers::parse(&s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Stores `answer` as the accepted answer of a part, keeping the other part untouched.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer);
+
+    let path = get_path(puzzle);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, answers.serialize())
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("1: 1154\n2: 6819\n");
+        assert_eq!(answers.get(1), Some("1154"));
+        assert_eq!(answers.get(2), Some("6819"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::parse("2: abc\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("abc"));
+    }
+
+    #[test]
+    fn round_trips_multiline_answers() {
+        let mut answers = Answers::default();
+        answers.set(1, "#..#\n#\\.#");
+        answers.set(2, "42");
+        let serialized = answers.serialize();
+        assert_eq!(serialized, "1: #..#\\n#\\\\.#\n2: 42\n");
+        assert_eq!(Answers::parse(&serialized), answers);
+    }
+}
@@ -0,0 +1,330 @@
+/// Module that keeps a local history of benchmark runs to detect performance regressions.
+/// Every timed run of `all` appends one JSON line per benched part to [`get_path`].
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::config;
+use crate::template::runner::{BenchStats, PartRecord};
+use crate::PuzzleId;
+
+/// The history file, e.g. `data/bench_history.jsonl`.
+#[must_use]
+pub fn get_path() -> PathBuf {
+    config::get().paths.data.join("bench_history.jsonl")
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Numbers the runs in the history, shared by all entries of a run.
+    /// Entries written without it are grouped into runs by their timestamp and commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<u64>,
+    /// Seconds since the unix epoch when the run finished, shared by all entries of a run.
+    pub timestamp: u64,
+    /// The commit checked out during the run, if any.
+    pub commit: Option<String>,
+    #[serde(flatten)]
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub stats: BenchStats,
+}
+
+/// The entries of one benchmark run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub id: Option<u64>,
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub entries: Vec<Entry>,
+}
+
+impl Run {
+    #[must_use]
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|e| e.puzzle == puzzle && e.part == part)
+    }
+
+    /// A short description of the run, e.g. `a1b2c3d @ 1733000000`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        format!(
+            "{} @ {}",
+            self.commit.as_deref().unwrap_or("unknown commit"),
+            self.timestamp
+        )
+    }
+}
+
+/// Appends the benched records of a run to the history file.
+pub fn append(records: &[PartRecord]) -> io::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = current_commit();
+    let run = read()?
+        .iter()
+        .filter_map(|run| run.id)
+        .max()
+        .map_or(1, |id| id + 1);
+
+    let lines: String = records
+        .iter()
+        .filter(|record| record.stats.is_benched())
+        .map(|record| Entry {
+            run: Some(run),
+            timestamp,
+            commit: commit.clone(),
+            puzzle: record.puzzle,
+            part: record.part,
+            stats: record.stats.clone(),
+        })
+        .map(|entry| serde_json::to_string(&entry).unwrap() + "\n")
+        .collect();
+
+    let path = get_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(lines.as_bytes())
+}
+
+/// Reads all runs from the history file, oldest first.
+pub fn read() -> io::Result<Vec<Run>> {
+    match fs::read_to_string(get_path()) {
+        Ok(s) => Ok(parse(&s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+fn parse(s: &str) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for entry in s
+        .lines()
+        .filter_map(|l| serde_json::from_str::<Entry>(l).ok())
+    {
+        match runs.last_mut() {
+            Some(run)
+                if run.id == entry.run
+                    && (entry.run.is_some()
+                        || (run.timestamp == entry.timestamp
+                            && run.commit == entry.commit)) =>
+            {
+                run.entries.push(entry);
+            }
+            _ => runs.push(Run {
+                id: entry.run,
+                timestamp: entry.timestamp,
+                commit: entry.commit.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    runs
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// How the median of a part changed between the baseline and the latest run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub baseline: Option<BenchStats>,
+    pub latest: BenchStats,
+}
+
+impl Change {
+    /// The relative change of the median, e.g. `0.1` for 10% slower.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn ratio(&self) -> Option<f64> {
+        let baseline = self.baseline.as_ref()?.median.as_nanos() as f64;
+        let latest = self.latest.median.as_nanos() as f64;
+        (baseline > 0.0).then(|| latest / baseline - 1.0)
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.ratio().is_some_and(|ratio| ratio > threshold)
+    }
+}
+
+/// Picks the run to compare the latest run (the last one in `runs`) against: the most recent earlier
+/// run whose commit starts with `commit`, or the previous run if no commit is given.
+#[must_use]
+pub fn find_baseline<'a>(
+    runs: &'a [Run],
+    commit: Option<&str>,
+) -> Option<&'a Run> {
+    let (_, earlier) = runs.split_last()?;
+
+    match commit {
+        Some(commit) => earlier.iter().rev().find(|run| {
+            run.commit.as_deref().is_some_and(|c| c.starts_with(commit))
+        }),
+        None => earlier.last(),
+    }
+}
+
+/// Compares every part of `latest` with the same part in `baseline`.
+#[must_use]
+pub fn diff(baseline: &Run, latest: &Run) -> Vec<Change> {
+    latest
+        .entries
+        .iter()
+        .map(|entry| Change {
+            puzzle: entry.puzzle,
+            part: entry.part,
+            baseline: baseline
+                .get(entry.puzzle, entry.part)
+                .map(|e| e.stats.clone()),
+            latest: entry.stats.clone(),
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{diff, find_baseline, parse, Entry};
+    use crate::template::runner::BenchStats;
+    use crate::{day, PuzzleId};
+    use std::time::Duration;
+
+    fn entry(
+        timestamp: u64,
+        commit: &str,
+        part: u8,
+        median_micros: u64,
+    ) -> String {
+        run_entry(Some(timestamp), timestamp, commit, part, median_micros)
+    }
+
+    fn run_entry(
+        run: Option<u64>,
+        timestamp: u64,
+        commit: &str,
+        part: u8,
+        median_micros: u64,
+    ) -> String {
+        let mut stats =
+            BenchStats::single(Duration::from_micros(median_micros));
+        stats.samples = 100;
+
+        serde_json::to_string(&Entry {
+            run,
+            timestamp,
+            commit: Some(commit.into()),
+            puzzle: PuzzleId::new(2025, day!(1)),
+            part,
+            stats,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn groups_entries_into_runs() {
+        let history = [
+            entry(1, "aaa", 1, 10),
+            entry(1, "aaa", 2, 20),
+            entry(2, "bbb", 1, 10),
+            "garbage".into(),
+        ]
+        .join("\n");
+
+        let runs = parse(&history);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].entries.len(), 2);
+        assert_eq!(runs[1].commit.as_deref(), Some("bbb"));
+        assert_eq!(runs[1].label(), "bbb @ 2");
+    }
+
+    #[test]
+    fn groups_entries_by_run_id() {
+        let history = [
+            run_entry(Some(1), 5, "aaa", 1, 10),
+            run_entry(Some(2), 5, "aaa", 1, 20),
+            run_entry(None, 4, "old", 1, 10),
+            run_entry(None, 4, "old", 2, 10),
+        ]
+        .join("\n");
+
+        let runs = parse(&history);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].id, Some(1));
+        assert_eq!(runs[1].id, Some(2));
+        assert_eq!(runs[2].id, None);
+        assert_eq!(runs[2].entries.len(), 2);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let history = [
+            entry(1, "aaa", 1, 100),
+            entry(1, "aaa", 2, 100),
+            entry(2, "bbb", 1, 105),
+            entry(2, "bbb", 2, 150),
+        ]
+        .join("\n");
+
+        let runs = parse(&history);
+        let changes = diff(&runs[0], &runs[1]);
+
+        assert!(!changes[0].is_regression(0.1));
+        assert!(changes[1].is_regression(0.1));
+        assert!((changes[1].ratio().unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn new_parts_are_not_regressions() {
+        let history =
+            [entry(1, "aaa", 1, 100), entry(2, "bbb", 2, 500)].join("\n");
+
+        let runs = parse(&history);
+        let changes = diff(&runs[0], &runs[1]);
+
+        assert_eq!(changes[0].ratio(), None);
+        assert!(!changes[0].is_regression(0.1));
+    }
+
+    #[test]
+    fn finds_baseline_by_commit() {
+        let history = [
+            entry(1, "aaa111", 1, 100),
+            entry(2, "bbb222", 1, 100),
+            entry(3, "aaa111", 1, 100),
+            entry(4, "ccc333", 1, 100),
+        ]
+        .join("\n");
+
+        let runs = parse(&history);
+        assert_eq!(find_baseline(&runs, None).unwrap().timestamp, 3);
+        assert_eq!(find_baseline(&runs, Some("bbb")).unwrap().timestamp, 2);
+        assert_eq!(find_baseline(&runs, Some("aaa")).unwrap().timestamp, 3);
+        assert_eq!(find_baseline(&runs, Some("ccc")), None);
+        assert_eq!(find_baseline(&runs[..1], None), None);
+    }
+}
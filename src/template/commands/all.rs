@@ -1,48 +1,292 @@
-use std::io;
+use std::{env, fmt::Display, fs, io, panic, str::FromStr};
+
+use itertools::Itertools;
+use rayon::prelude::*;
 
 use crate::template::{
+    bench_history, config,
     readme_benchmarks::{self, Timings},
+    runner::{print_record, OutputFormat, PartRecord, Solution},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, PuzzleId};
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+/// The years `all` runs: a single one, or every year with solutions in `src/bin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Years {
+    One(u16),
+    Every,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+impl Years {
+    /// The years to print, in order.
+    #[must_use]
+    pub fn resolve(self) -> Vec<u16> {
+        match self {
+            Years::One(year) => vec![year],
+            Years::Every => years_present(),
         }
+    }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    #[must_use]
+    pub fn contains(self, year: u16) -> bool {
+        match self {
+            Years::One(y) => y == year,
+            Years::Every => true,
+        }
+    }
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+impl Display for Years {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Years::One(year) => write!(f, "{year}"),
+            Years::Every => write!(f, "all"),
+        }
+    }
+}
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+impl FromStr for Years {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Years::Every),
+            s => s.parse().map(Years::One),
         }
-    });
+    }
+}
+
+/// The years that have a directory of solutions in `src/bin`, in order.
+#[must_use]
+pub fn years_present() -> Vec<u16> {
+    let Ok(entries) = fs::read_dir(&config::get().paths.bin) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            path.is_dir()
+                .then(|| path.file_name()?.to_str()?.parse().ok())
+                .flatten()
+        })
+        .sorted()
+        .collect()
+}
+
+/// How `all` runs the solutions, read from its arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AllOptions {
+    pub release: bool,
+    pub time: bool,
+    pub parallel: bool,
+    pub memory: bool,
+    pub timeout: Option<u64>,
+    pub format: OutputFormat,
+    /// The only part to run.
+    pub part: Option<u8>,
+}
+
+/// Runs all solutions of `years` through the `aoc-all` binary and updates the README benchmarks when
+/// timed. The README only lists a single year and both parts, so it is left alone when running every
+/// year or a single `part`.
+pub fn handle(years: Years, options: &AllOptions) {
+    let AllOptions {
+        release: is_release,
+        time: is_timed,
+        parallel: is_parallel,
+        memory: is_memory,
+        timeout,
+        format,
+        part,
+    } = *options;
+
+    let timeout = timeout.map(|secs| secs.to_string());
+    let part_arg = part.map(|part| part.to_string());
+    let years_arg = years.to_string();
+    let mut args = vec!["--year", &years_arg];
+
+    if let Some(part) = &part_arg {
+        args.push("--part");
+        args.push(part);
+    }
 
     if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+        // mirror `--time` flag to child invocations.
+        args.push("--time");
+    }
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+    if let Some(timeout) = &timeout {
+        args.push("--timeout");
+        args.push(timeout);
+    }
+
+    if is_parallel {
+        args.push("--parallel");
+    }
+
+    let records =
+        child_commands::run_bin("aoc-all", is_release, is_memory, &args)
+            .unwrap();
+    let records = print_days(&years.resolve(), records, format);
+
+    if is_timed {
+        let timings = timings(&records);
+        let total_millis = total_millis(&timings);
+
+        if format == OutputFormat::Text {
+            print_total(total_millis);
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+            if let (Years::One(_), None) = (&years, part) {
+                match readme_benchmarks::update(timings, total_millis) {
+                    Ok(()) => eprintln!(
+                        "Successfully updated README with benchmarks."
+                    ),
+                    Err(_) => {
+                        eprintln!("Failed to update readme with benchmarks.");
+                    }
                 }
             }
+
+            if let Err(e) = bench_history::append(&records) {
+                eprintln!("Failed to append benchmarks to history: {e}");
+            }
         }
     }
 }
 
+/// Runs the registered solutions in the current process, optionally in parallel.
+/// This is the entry point of the `aoc-all` binary, which runs every year unless `--year` is passed.
+///
+/// A part that times out is abandoned: its thread keeps running in the background until the binary
+/// exits, while the remaining days carry on.
+pub fn handle_in_process(solutions: &[Solution]) {
+    let format = OutputFormat::from_args();
+    let is_timed = env::args().any(|x| x == "--time");
+    let years = env::args()
+        .skip_while(|x| x != "--year")
+        .nth(1)
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(Years::Every);
+
+    let solutions = solutions
+        .iter()
+        .filter(|solution| years.contains(solution.puzzle.year))
+        .collect_vec();
+
+    let records: Box<dyn Iterator<Item = PartRecord>> =
+        if env::args().any(|x| x == "--parallel") {
+            let records: Vec<_> =
+                solutions.par_iter().map(|s| run_solution(s)).collect();
+            Box::new(records.into_iter().flatten())
+        } else {
+            Box::new(solutions.iter().flat_map(|s| run_solution(s)))
+        };
+
+    let years = match years {
+        Years::One(year) => vec![year],
+        Years::Every => {
+            solutions.iter().map(|s| s.puzzle.year).dedup().collect()
+        }
+    };
+
+    let records = print_days(&years, records, format);
+
+    if is_timed && format == OutputFormat::Text {
+        print_total(total_millis(&timings(&records)));
+    }
+}
+
+/// Runs a registered solution, keeping a panic outside of its parts (e.g. a missing input file) from
+/// aborting the remaining days.
+fn run_solution(solution: &Solution) -> Vec<PartRecord> {
+    panic::catch_unwind(solution.run).unwrap_or_else(|_| {
+        eprintln!("Puzzle {} failed to run.", solution.puzzle);
+        vec![]
+    })
+}
+
+/// Prints the records of every day of `years` in order, marking days without records as not solved.
+/// A header is printed per year when there is more than one.
+/// `records` must be sorted by puzzle. Returns the printed records.
+fn print_days(
+    years: &[u16],
+    records: impl Iterator<Item = PartRecord>,
+    format: OutputFormat,
+) -> Vec<PartRecord> {
+    let mut records = records.peekable();
+    let mut printed: Vec<PartRecord> = vec![];
+    let is_text = format == OutputFormat::Text;
+    let has_headers = years.len() > 1;
+
+    let puzzles = years.iter().flat_map(|&year| {
+        all_days(year).map(move |day| PuzzleId::new(year, day))
+    });
+
+    puzzles.for_each(|puzzle| {
+        if is_text {
+            if puzzle.day == 1 && has_headers {
+                if puzzle.year != years[0] {
+                    println!();
+                }
+
+                println!("{ANSI_BOLD}Year {}{ANSI_RESET}", puzzle.year);
+                println!("======");
+            }
+
+            if puzzle.day > 1 || has_headers {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+            println!("------");
+        }
+
+        let day_records = records
+            .peeking_take_while(|record| record.puzzle == puzzle)
+            .collect_vec();
+
+        if day_records.is_empty() {
+            if is_text {
+                println!("Not solved.");
+            }
+            return;
+        }
+
+        for record in &day_records {
+            match format {
+                OutputFormat::Text => print_record(record),
+                OutputFormat::Json => println!("{}", record.to_json()),
+            }
+        }
+
+        printed.extend(day_records);
+    });
+
+    printed
+}
+
+/// Collects the timings of each day. `records` must be sorted by puzzle.
+fn timings(records: &[PartRecord]) -> Vec<Timings> {
+    records
+        .chunk_by(|a, b| a.puzzle == b.puzzle)
+        .map(|day_records| {
+            child_commands::parse_exec_time(day_records, day_records[0].puzzle)
+        })
+        .collect()
+}
+
+fn total_millis(timings: &[Timings]) -> f64 {
+    timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+}
+
+fn print_total(total_millis: f64) {
+    println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
@@ -57,44 +301,45 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    readme_benchmarks::get_path_for_bin(puzzle)
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+/// All solutions are compiled into their own binaries as well as the `aoc-all` binary.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they emit.
+pub(crate) mod child_commands {
+    use super::Error;
+    use crate::template::runner::PartRecord;
+    use crate::PuzzleId;
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
+        iter,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Run a solution bin (or `aoc-all`) in json mode and lazily yield the records it prints.
+    pub fn run_bin(
+        bin: &str,
+        is_release: bool,
+        is_memory: bool,
+        extra_args: &[&str],
+    ) -> Result<impl Iterator<Item = PartRecord>, Error> {
+        let mut args = vec!["run", "--quiet", "--bin", bin];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if is_memory {
+            args.extend(["--features", "memory"]);
         }
 
+        args.extend(["--", "--format", "json"]);
+        args.extend(extra_args);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr and any non-record output while grabbing the records from stdout.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -105,89 +350,71 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let mut lines = stdout.lines();
+        let mut child = Some((cmd, thread));
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        Ok(iter::from_fn(move || {
+            for line in lines.by_ref() {
+                let line = line.unwrap();
+                match PartRecord::from_json(&line) {
+                    Some(record) => return Some(record),
+                    None => println!("{line}"),
+                }
+            }
 
-        Ok(output)
+            if let Some((mut cmd, thread)) = child.take() {
+                thread.join().unwrap();
+                let _ = cmd.wait();
+            }
+
+            None
+        }))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
+    pub fn parse_exec_time(
+        records: &[PartRecord],
+        puzzle: PuzzleId,
+    ) -> super::Timings {
         let mut timings = super::Timings {
-            day,
+            puzzle,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            peak_memory: records
+                .iter()
+                .filter_map(|r| r.memory)
+                .map(|m| m.peak_bytes)
+                .max(),
         };
 
-        output
+        records
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            .filter(|record| record.stats.is_benched())
+            .for_each(|record| {
+                let timing_str = Some(record.stats.summary());
+
+                match record.part {
+                    1 => timings.part_1 = timing_str,
+                    2 => timings.part_2 = timing_str,
+                    _ => {}
                 }
 
+                #[allow(clippy::cast_precision_loss)]
+                let nanos = record.stats.median.as_nanos() as f64;
                 timings.total_nanos += nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     macro_rules! assert_approx_eq {
         ($a:expr, $b:expr) => {{
             let (a, b) = (&$a, &$b);
@@ -200,55 +427,87 @@ mod child_commands {
         }};
     }
 
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     mod tests {
         use super::parse_exec_time;
+        use std::time::Duration;
+
+        use crate::template::runner::{BenchStats, PartRecord};
+        use crate::{day, PuzzleId};
 
-        use crate::day;
+        const PUZZLE: PuzzleId = PuzzleId::new(2025, day!(1));
+
+        fn record(
+            part: u8,
+            answer: Option<&str>,
+            stats: BenchStats,
+        ) -> PartRecord {
+            PartRecord::new(PUZZLE, part, answer.as_ref(), stats)
+        }
+
+        fn bench(median_nanos: u64, std_dev_nanos: u64) -> BenchStats {
+            BenchStats {
+                samples: 100,
+                median: Duration::from_nanos(median_nanos),
+                std_dev: Duration::from_nanos(std_dev_nanos),
+                ..BenchStats::single(Duration::from_nanos(median_nanos))
+            }
+        }
 
         #[test]
         fn test_well_formed() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(1, Some("0"), bench(74, 2)),
+                    record(2, Some("10"), bench(74_130_000, 1_200_000)),
                 ],
-                day!(1),
+                PUZZLE,
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns ± 2.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms ± 1.2ms");
         }
 
         #[test]
-        fn test_patterns_in_input() {
+        fn test_unbenched_runs() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(
+                        1,
+                        Some("0"),
+                        BenchStats::single(Duration::from_secs(2)),
+                    ),
+                    record(2, Some("10"), bench(100_000_000, 0)),
                 ],
-                day!(1),
+                PUZZLE,
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 100000000_f64);
+            assert!(res.part_1.is_none());
+            assert_eq!(res.part_2.unwrap(), "100.0ms ± 0.0ns");
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let res = parse_exec_time(&[], PUZZLE);
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
+        }
+
+        #[test]
+        fn test_record_round_trip() {
+            let original = record(2, Some("42"), bench(1500, 20));
+            let json = original.to_json();
+            assert_eq!(
+                json,
+                concat!(
+                    r#"{"year":2025,"day":1,"part":2,"status":"solved","answer":"42","stats":{"samples":100,"#,
+                    r#""min_ns":1500,"median_ns":1500,"p95_ns":1500,"mean_ns":1500,"#,
+                    r#""std_dev_ns":20,"outliers":0}}"#
+                )
+            );
+            assert_eq!(PartRecord::from_json(&json), Some(original));
+            assert_eq!(PartRecord::from_json("Part 1: 42 (1.5µs)"), None);
         }
     }
 }
@@ -0,0 +1,79 @@
+use std::process;
+
+use crate::template::runner::part_label;
+use crate::template::{bench_history, ANSI_BOLD, ANSI_RESET};
+
+/// Compares the latest benchmark run with a baseline run from the history.
+/// Only parts of `year` are compared if given.
+/// Exits with a non-zero status if any part got slower than `threshold` percent.
+pub fn handle(year: Option<u16>, baseline: Option<&str>, threshold: f64) {
+    let runs = bench_history::read().unwrap_or_else(|e| {
+        eprintln!(
+            "Failed to read \"{}\": {e}",
+            bench_history::get_path().display()
+        );
+        process::exit(1);
+    });
+
+    let Some(latest) = runs.last() else {
+        eprintln!("No benchmark history yet. Run `cargo time` to record one.");
+        process::exit(1);
+    };
+
+    let Some(base) = bench_history::find_baseline(&runs, baseline) else {
+        match baseline {
+            Some(commit) => eprintln!(
+                "No earlier benchmark run found for commit \"{commit}\"."
+            ),
+            None => eprintln!(
+                "Only one benchmark run recorded, nothing to compare against."
+            ),
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "{ANSI_BOLD}Comparing {} against {}{ANSI_RESET}\n",
+        latest.label(),
+        base.label()
+    );
+
+    let mut regressions = 0;
+
+    for change in bench_history::diff(base, latest)
+        .into_iter()
+        .filter(|change| year.is_none_or(|year| change.puzzle.year == year))
+    {
+        let label = format!(
+            "{} Day {} {}",
+            change.puzzle.year,
+            change.puzzle.day,
+            part_label(change.part)
+        );
+        let latest = format!("{:.1?}", change.latest.median);
+
+        match (&change.baseline, change.ratio()) {
+            (Some(baseline), Some(ratio)) => {
+                let verdict = if change.is_regression(threshold / 100.0) {
+                    regressions += 1;
+                    " ✖ slower"
+                } else {
+                    ""
+                };
+
+                println!(
+                    "{label}: {:.1?} → {latest} ({:+.1}%){verdict}",
+                    baseline.median,
+                    ratio * 100.0
+                );
+            }
+            _ => println!("{label}: {latest} (new)"),
+        }
+    }
+
+    println!("\n{ANSI_BOLD}{regressions} regression(s) above {threshold}%{ANSI_RESET}");
+
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
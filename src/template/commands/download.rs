@@ -1,15 +1,14 @@
-use crate::template::aoc_cli;
-use crate::Day;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::provider;
+use crate::PuzzleId;
+
+pub fn handle(puzzle: PuzzleId) {
+    let result =
+        provider::get_checked().and_then(|provider| provider.download(puzzle));
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = result {
+        eprintln!("{e}");
         process::exit(1);
     };
 }
@@ -1,38 +1,219 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    ops::Range,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use itertools::Itertools;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+use crate::template::description::{self, Example};
+use crate::template::examples::Examples;
+use crate::template::{config, get_year, module_template, provider};
+use crate::{Day, PuzzleId};
 
-pub fn part_one(input: &str) -> Option<u32> {
-    None
+const EXAMPLES_TEMPLATE: &str = r#"# Expected answers for the example inputs in this directory.
+# Every part with an expected answer becomes a test, add more [[example]] entries as needed.
+[[example]]
+file = "DAY_PADDED.txt"
+# part_one = 0
+# part_two = 0
+"#;
+
+/// Builds the examples file for examples extracted from the puzzle description.
+fn extracted_examples_file(day: Day, examples: &[Example]) -> String {
+    let mut out = String::from(
+        "# Expected answers for the example inputs in this directory.\n\
+         # Every part with an expected answer becomes a test, add more [[example]] entries as needed.\n\
+         # The examples and answers were extracted from the puzzle description, check them before relying on them.\n",
+    );
+
+    for (i, example) in examples.iter().enumerate() {
+        out.push_str(&example_entry(&example_file_name(day, i), example));
+    }
+
+    out
+}
+
+/// Builds the `[[example]]` entry of an example file, with a commented stub for unknown answers.
+fn example_entry(file: &str, example: &Example) -> String {
+    let mut out = format!("[[example]]\nfile = \"{file}\"\n");
+
+    for (name, answer) in [
+        ("part_one", &example.part_one),
+        ("part_two", &example.part_two),
+    ] {
+        match answer {
+            Some(answer) => {
+                out.push_str(&format!("{name} = {}\n", answer_value(answer)));
+            }
+            None => out.push_str(&format!("# {name} = 0\n")),
+        }
+    }
+
+    out
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// Writes an answer as a TOML integer if it reads back the same, e.g. `42`, and as a string otherwise,
+/// e.g. `"0042"` or `"abc"`.
+fn answer_value(answer: &str) -> toml::Value {
+    match answer.parse::<i64>() {
+        Ok(n) if n.to_string() == answer => toml::Value::from(n),
+        _ => toml::Value::from(answer),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Updates the examples file for examples extracted again from the description and returns its new content.
+/// `files` holds the example file of each extracted example and whether it existed before. Answers that
+/// no entry declares yet are merged into the existing entry of their file, replacing its commented stub.
+/// Files without an entry get a new one appended, unless they existed before and have no new answers.
+fn update_manifest(
+    manifest: &str,
+    declared: &Examples,
+    examples: &[Example],
+    files: &[(String, bool)],
+) -> String {
+    let mut lines = manifest.lines().map(String::from).collect_vec();
+    let mut appended = String::new();
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    for (example, (file, existed)) in examples.iter().zip(files) {
+        let missing = |part: u8, answer: &Option<String>| {
+            answer.clone().filter(|_| {
+                !declared
+                    .examples
+                    .iter()
+                    .any(|e| &e.file == file && e.expected(part).is_some())
+            })
+        };
+
+        let entry = Example {
+            input: String::new(),
+            part_one: missing(1, &example.part_one),
+            part_two: missing(2, &example.part_two),
+        };
+
+        if find_entry(&lines, file).is_some() {
+            for (name, answer) in
+                [("part_one", &entry.part_one), ("part_two", &entry.part_two)]
+            {
+                if let (Some(answer), Some(range)) =
+                    (answer, find_entry(&lines, file))
+                {
+                    let line = format!("{name} = {}", answer_value(answer));
+                    set_entry_line(&mut lines, range, name, line);
+                }
+            }
+        } else if !existed
+            || entry.part_one.is_some()
+            || entry.part_two.is_some()
+        {
+            appended.push_str(&example_entry(file, &entry));
+        }
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    let mut out = lines.join("\n");
+    if manifest.ends_with('\n') {
+        out.push('\n');
     }
+    if !appended.is_empty() {
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(&appended);
+    }
+
+    out
+}
+
+/// Finds the lines of the first `[[example]]` entry of `file`, up to the next table header.
+fn find_entry(lines: &[String], file: &str) -> Option<Range<usize>> {
+    let starts = lines
+        .iter()
+        .positions(|line| line.trim() == "[[example]]")
+        .collect_vec();
+
+    starts.into_iter().find_map(|start| {
+        let end = (start + 1..lines.len())
+            .find(|&i| lines[i].trim_start().starts_with('['))
+            .unwrap_or(lines.len());
+
+        lines[start + 1..end]
+            .iter()
+            .any(|line| {
+                line.parse::<toml::Table>().is_ok_and(|table| {
+                    table.get("file").and_then(toml::Value::as_str)
+                        == Some(file)
+                })
+            })
+            .then_some(start..end)
+    })
+}
+
+/// Sets the `name` key of the entry in `range` to `line`, replacing its commented stub (e.g.
+/// `# part_two = 0`) or else following the last key of the entry.
+fn set_entry_line(
+    lines: &mut Vec<String>,
+    range: Range<usize>,
+    name: &str,
+    line: String,
+) {
+    let is_stub = |l: &String| {
+        l.trim_start().starts_with('#')
+            && l.trim_start_matches(['#', ' '])
+                .split('=')
+                .next()
+                .map(str::trim)
+                == Some(name)
+    };
+    let is_key = |l: &String| {
+        let l = l.trim();
+        !l.is_empty() && !l.starts_with('#')
+    };
+
+    match range.clone().find(|&i| is_stub(&lines[i])) {
+        Some(i) => lines[i] = line,
+        None => {
+            let last = range.rev().find(|&i| is_key(&lines[i])).unwrap();
+            lines.insert(last + 1, line);
+        }
+    }
+}
+
+/// The file of the `i`-th example, e.g. `01.txt` for the first and `01-2.txt` for the second.
+fn example_file_name(day: Day, i: usize) -> String {
+    match i {
+        0 => format!("{day}.txt"),
+        i => format!("{day}-{}.txt", i + 1),
+    }
+}
+
+/// Finds the example file of an extracted example: the one with the same input, or else the first
+/// missing or empty one. Returns the file name and whether it holds the example already.
+fn find_example_file(
+    examples_dir: &Path,
+    day: Day,
+    input: &str,
+) -> (String, bool) {
+    let mut free = None;
+
+    for i in 0.. {
+        let name = example_file_name(day, i);
+
+        match fs::read_to_string(examples_dir.join(&name)) {
+            Ok(content) if content.trim_end() == input.trim_end() => {
+                return (name, true)
+            }
+            Ok(content) if content.trim().is_empty() => {
+                free.get_or_insert(name);
+            }
+            Ok(_) => {}
+            Err(_) => return (free.unwrap_or(name), false),
+        }
+    }
+
+    unreachable!()
 }
-"#;
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
@@ -42,10 +223,86 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create(true).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Registers the solution binary of a puzzle in `Cargo.toml`, which does not discover binaries in
+/// the year directories of `src/bin` by itself.
+fn register_bin(
+    puzzle: PuzzleId,
+    module_path: &str,
+) -> Result<bool, io::Error> {
+    let manifest = fs::read_to_string("Cargo.toml")?;
+    let name = format!("name = \"{puzzle}\"");
+
+    if manifest.lines().any(|line| line.trim() == name) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open("Cargo.toml")?;
+    write!(file, "\n[[bin]]\n{name}\npath = \"{module_path}\"\n")?;
+    Ok(true)
+}
+
+/// Creates the solution module and data files of a puzzle.
+/// The module is rendered from `template` and returns `answer` from its parts, both default to the config.
+/// With `download`, the input and description are downloaded first and the examples are extracted from the
+/// description.
+pub fn handle(
+    puzzle: PuzzleId,
+    template: Option<&str>,
+    answer: Option<&str>,
+    download: bool,
+) {
+    let config = config::get();
+    let template = template.unwrap_or(&config.scaffold.template);
+    let answer = answer.unwrap_or(&config.scaffold.answer);
+
+    let module = match module_template::load(template) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let paths = &config.paths;
+    let day = puzzle.day;
+    let inputs_dir = paths.folder(puzzle.year, "inputs");
+    let examples_dir = paths.folder(puzzle.year, "examples");
+    let puzzles_dir = paths.folder(puzzle.year, "puzzles");
+    let bin_dir = paths.bin_dir(puzzle.year);
+    let input_path = format!("{}/{day}.txt", inputs_dir.display());
+    let examples_path = format!("{}/{day}.toml", examples_dir.display());
+    let module_path = format!("{}/{day}.rs", bin_dir.display());
+
+    for dir in [&inputs_dir, &examples_dir, &puzzles_dir, &bin_dir] {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create directory \"{}\": {e}", dir.display());
+            process::exit(1);
+        }
+    }
+
+    if download {
+        if let Err(e) = provider::get_checked()
+            .and_then(|provider| provider.download(puzzle))
+        {
+            eprintln!("Failed to download puzzle: {e}");
+            process::exit(1);
+        }
+    }
+
+    let description =
+        fs::read_to_string(provider::get_puzzle_path(puzzle)).ok();
+
+    let title = description
+        .as_deref()
+        .and_then(module_template::parse_title)
+        .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()));
+
+    let examples = match &description {
+        Some(description) if download => {
+            description::extract_examples(description)
+        }
+        _ => vec![],
+    };
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -56,9 +313,7 @@ pub fn handle(day: Day) {
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
+        module_template::render(&module, puzzle, &title, answer).as_bytes(),
     ) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
@@ -69,26 +324,317 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    if !download {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if examples.is_empty() {
+        let example_path =
+            format!("{}/{}", examples_dir.display(), example_file_name(day, 0));
+
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    for (i, example) in examples.iter().enumerate() {
+        let example_path =
+            format!("{}/{}", examples_dir.display(), example_file_name(day, i));
+
+        match safe_create_file(&example_path)
+            .and_then(|mut file| file.write_all(example.input.as_bytes()))
+        {
+            Ok(()) => {
+                println!("Extracted example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!(
+                    "Failed to write example file \"{}\": {e}",
+                    &example_path
+                );
+            }
         }
+    }
+
+    let examples_file = if examples.is_empty() {
+        EXAMPLES_TEMPLATE.replace("DAY_PADDED", &day.to_string())
+    } else {
+        extracted_examples_file(day, &examples)
+    };
+
+    match safe_create_file(&examples_path) {
+        Ok(mut file) => match file.write_all(examples_file.as_bytes()) {
+            Ok(()) => {
+                println!("Created examples file \"{}\"", &examples_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write examples file: {e}");
+                process::exit(1);
+            }
+        },
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to create examples file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    match register_bin(puzzle, &module_path) {
+        Ok(true) => {
+            println!("Registered binary \"{puzzle}\" in \"Cargo.toml\"")
         }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to register binary in Cargo.toml: {e}");
             process::exit(1);
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    if get_year() == Some(puzzle.year) {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {day} --year {}` to run your solution.",
+            puzzle.year
+        );
+    }
+}
+
+/// Refreshes a scaffolded puzzle, e.g. after solving part one: downloads the description again, writes the
+/// examples that are new to the example files and appends the answers that are missing to the examples file.
+/// Adds the example tests to the module if it has none. Existing code, examples and answers are left as is.
+pub fn update(puzzle: PuzzleId) {
+    let paths = &config::get().paths;
+    let day = puzzle.day;
+    let examples_dir = paths.folder(puzzle.year, "examples");
+    let examples_path = format!("{}/{day}.toml", examples_dir.display());
+    let module_path =
+        format!("{}/{day}.rs", paths.bin_dir(puzzle.year).display());
+
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        if get_year() == Some(puzzle.year) {
+            eprintln!("Failed to read module file \"{module_path}\", type `cargo scaffold {day}` to create it first.");
+        } else {
+            eprintln!(
+                "Failed to read module file \"{module_path}\", type `cargo scaffold {day} --year {}` to create it first.",
+                puzzle.year
+            );
+        }
+        process::exit(1);
+    };
+
+    if let Err(e) = fs::create_dir_all(&examples_dir) {
+        eprintln!(
+            "Failed to create directory \"{}\": {e}",
+            examples_dir.display()
+        );
+        process::exit(1);
+    }
+
+    if let Err(e) =
+        provider::get_checked().and_then(|provider| provider.download(puzzle))
+    {
+        eprintln!("Failed to download puzzle: {e}");
+        process::exit(1);
+    }
+
+    let description =
+        match fs::read_to_string(provider::get_puzzle_path(puzzle)) {
+            Ok(description) => description,
+            Err(e) => {
+                eprintln!("Failed to read puzzle description: {e}");
+                process::exit(1);
+            }
+        };
+
+    let manifest = match fs::read_to_string(&examples_path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            eprintln!("Failed to read examples file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let declared = match Examples::parse(&manifest) {
+        Ok(declared) => declared,
+        Err(e) => {
+            eprintln!("Failed to parse examples file \"{examples_path}\": {e}");
+            process::exit(1);
+        }
+    };
+
+    let examples = description::extract_examples(&description);
+    let mut files = vec![];
+
+    for example in &examples {
+        let (file, existed) =
+            find_example_file(&examples_dir, day, &example.input);
+        let example_path = format!("{}/{file}", examples_dir.display());
+
+        if !existed {
+            match fs::write(&example_path, &example.input) {
+                Ok(()) => println!("Extracted example file \"{example_path}\""),
+                Err(e) => {
+                    eprintln!(
+                        "Failed to write example file \"{example_path}\": {e}"
+                    );
+                    process::exit(1);
+                }
+            }
+        }
+
+        files.push((file, existed));
+    }
+
+    let updated = update_manifest(&manifest, &declared, &examples, &files);
+
+    if updated == manifest {
+        println!("No new examples or answers in the puzzle description.");
+    } else {
+        match fs::write(&examples_path, updated) {
+            Ok(()) => println!("Added examples to \"{examples_path}\", check them before relying on them."),
+            Err(e) => {
+                eprintln!("Failed to write examples file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if !module.contains("example_tests!") {
+        let separator = match module.ends_with('\n') {
+            true if module.ends_with("\n\n") => "",
+            true => "\n",
+            false => "\n\n",
+        };
+        let tests = format!(
+            "{separator}advent_of_code::example_tests!({}, {});\n",
+            puzzle.year,
+            day.into_inner()
+        );
+
+        match OpenOptions::new()
+            .append(true)
+            .open(&module_path)
+            .and_then(|mut file| file.write_all(tests.as_bytes()))
+        {
+            Ok(()) => println!("Added example tests to \"{module_path}\""),
+            Err(e) => {
+                eprintln!("Failed to write module file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{example_entry, update_manifest, Example};
+    use crate::template::examples::Examples;
+
+    fn example(
+        input: &str,
+        part_one: Option<&str>,
+        part_two: Option<&str>,
+    ) -> Example {
+        Example {
+            input: input.into(),
+            part_one: part_one.map(Into::into),
+            part_two: part_two.map(Into::into),
+        }
+    }
+
+    /// Runs an update of `manifest` like `scaffold --update` does.
+    fn update(
+        manifest: &str,
+        examples: &[Example],
+        files: &[(&str, bool)],
+    ) -> String {
+        let declared = Examples::parse(manifest).unwrap();
+        let files = files
+            .iter()
+            .map(|(file, existed)| (file.to_string(), *existed))
+            .collect::<Vec<_>>();
+
+        update_manifest(manifest, &declared, examples, &files)
+    }
+
+    #[test]
+    fn adds_only_missing_answers() {
+        let examples = [
+            example("1\n", Some("3"), Some("2")),
+            example("2\n", None, Some("12")),
+        ];
+
+        assert_eq!(
+            update(
+                "[[example]]\nfile = \"01.txt\"\npart_one = 3\n",
+                &examples,
+                &[("01.txt", true), ("01-2.txt", false)]
+            ),
+            "[[example]]\nfile = \"01.txt\"\npart_one = 3\npart_two = 2\n\
+             [[example]]\nfile = \"01-2.txt\"\n# part_one = 0\npart_two = 12\n"
+        );
+    }
+
+    #[test]
+    fn merges_answers_into_existing_entries() {
+        let manifest = "# Expected answers.\n[[example]]\nfile = \"01.txt\"\n\
+                        # part_one = 0\n# part_two = 0\nparams = { size = 7 }\n\n\
+                        [[example]]\nfile = \"01-2.txt\"\npart_one = 5\n";
+        let files = [("01.txt", true), ("01-2.txt", true)];
+
+        let after_part_one =
+            update(manifest, &[example("1\n", Some("3"), None)], &files);
+        assert_eq!(
+            after_part_one,
+            "# Expected answers.\n[[example]]\nfile = \"01.txt\"\n\
+             part_one = 3\n# part_two = 0\nparams = { size = 7 }\n\n\
+             [[example]]\nfile = \"01-2.txt\"\npart_one = 5\n"
+        );
+
+        let examples = [
+            example("1\n", Some("3"), Some("0042")),
+            example("2\n", Some("5"), Some("8")),
+        ];
+        let after_part_two = update(&after_part_one, &examples, &files);
+        assert_eq!(
+            after_part_two,
+            "# Expected answers.\n[[example]]\nfile = \"01.txt\"\n\
+             part_one = 3\npart_two = \"0042\"\nparams = { size = 7 }\n\n\
+             [[example]]\nfile = \"01-2.txt\"\npart_one = 5\npart_two = 8\n"
+        );
+
+        assert_eq!(update(&after_part_two, &examples, &files), after_part_two);
+    }
+
+    #[test]
+    fn keeps_answers_verbatim() {
+        assert_eq!(
+            example_entry("01.txt", &example("", Some("0042"), Some("-7"))),
+            "[[example]]\nfile = \"01.txt\"\npart_one = \"0042\"\npart_two = -7\n"
+        );
+    }
+
+    #[test]
+    fn skips_known_examples() {
+        let manifest = "[[example]]\nfile = \"01.txt\"\npart_one = 3\n[[example]]\nfile = \"01.txt\"\npart_two = 2\n";
+        let examples = [example("1\n", Some("3"), Some("2"))];
+
+        assert_eq!(update(manifest, &examples, &[("01.txt", true)]), manifest);
+    }
 }
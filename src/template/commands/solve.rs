@@ -1,31 +1,197 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    path::PathBuf,
+    process::{self, Command, Stdio},
+};
 
-use crate::Day;
+use crate::template::context::InputSource;
+use crate::template::runner::OutputFormat;
+use crate::template::{config, watch};
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// The files a solution depends on: its module, the library, its input and example files and the
+/// input file it runs on, if any.
+fn watched_paths(puzzle: PuzzleId, input: &InputSource) -> Vec<PathBuf> {
+    let paths = &config::get().paths;
+    let day = puzzle.day.to_string();
+
+    let mut watched = vec![
+        paths.bin_dir(puzzle.year).join(format!("{day}.rs")),
+        ["src", "lib.rs"].iter().collect(),
+        paths
+            .folder(puzzle.year, "inputs")
+            .join(format!("{day}.txt")),
+    ];
+
+    if let InputSource::File(path) = input {
+        watched.push(path.clone());
+    }
+
+    if let Ok(entries) = fs::read_dir(paths.folder(puzzle.year, "examples")) {
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name().and_then(|name| name.to_str()).is_some_and(
+                    |name| {
+                        name.starts_with(&format!("{day}."))
+                            || name.starts_with(&format!("{day}-"))
+                    },
+                )
+            })
+            .collect();
+
+        examples.sort();
+        watched.extend(examples);
+    }
+
+    watched
+}
+
+fn run(cmd_args: &[String]) {
+    let mut cmd = Command::new("cargo")
+        .args(cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap();
+}
+
+/// How `solve` runs a solution, read from its arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    pub time: bool,
+    /// The part to submit.
+    pub submit: Option<u8>,
+    pub wait: bool,
+    pub memory: bool,
+    pub timeout: Option<u64>,
+    pub format: OutputFormat,
+    /// The only part to run.
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub watch: bool,
+    /// Run the tests instead of the solution.
+    pub tests: bool,
+}
+
+/// Runs the solution of a puzzle on `input`, or only its tests on the examples with `tests`.
+/// With `part`, only that part is run, benched and tested.
+/// With `watch`, it is rebuilt and run again whenever its module, `src/lib.rs` or its data change.
+pub fn handle(puzzle: PuzzleId, options: &SolveOptions) {
+    let SolveOptions {
+        release,
+        time,
+        submit: submit_part,
+        wait,
+        memory,
+        timeout,
+        format,
+        part,
+        ref input,
+        watch,
+        tests,
+    } = *options;
+
+    if watch && submit_part.is_some() {
+        eprintln!("`--watch` cannot be combined with `--submit`.");
+        process::exit(1);
+    }
+
+    if let (Some(part), Some(submit_part)) = (part, submit_part) {
+        if part != submit_part {
+            eprintln!("Cannot submit part {submit_part} when only running part {part}.");
+            process::exit(1);
+        }
+    }
+
+    if *input != InputSource::Real && submit_part.is_some() {
+        eprintln!("Only answers for the puzzle input can be submitted, remove `--submit` or the input option.");
+        process::exit(1);
+    }
+
+    if *input == InputSource::Stdin && watch {
+        eprintln!("`--watch` cannot be combined with `--stdin`, pass the input with `--input` instead.");
+        process::exit(1);
+    }
+
+    if tests {
+        let mut cmd_args =
+            vec!["test".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+        if release {
+            cmd_args.push("--release".to_string());
+        }
+
+        // the tests of a part are named after it, e.g. `part_two_example_1` or `test_part_two`.
+        match part {
+            Some(1) => {
+                cmd_args.extend(["--".to_string(), "part_one".to_string()])
+            }
+            Some(2) => {
+                cmd_args.extend(["--".to_string(), "part_two".to_string()])
+            }
+            _ => {}
+        }
+
+        if watch {
+            watch::watch(|| watched_paths(puzzle, input), || run(&cmd_args));
+        }
+
+        run(&cmd_args);
+        return;
+    }
+
+    let mut cmd_args =
+        vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if memory {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("memory".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if wait {
+            cmd_args.push("--wait".to_string());
+        }
     }
 
     if time {
         cmd_args.push("--time".to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.to_string());
+    }
 
-    cmd.wait().unwrap();
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.as_str().to_string());
+    }
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
+    if watch {
+        watch::watch(|| watched_paths(puzzle, input), || run(&cmd_args));
+    }
+
+    run(&cmd_args);
 }
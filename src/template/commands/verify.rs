@@ -0,0 +1,221 @@
+use std::{fmt::Display, path::Path, process};
+
+use itertools::Itertools;
+
+use crate::template::{
+    answers,
+    commands::all::{child_commands, get_path_for_bin, Years},
+    runner::{PartRecord, PARSE},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{Answer, Day, PuzzleId};
+
+/// The outcome of comparing a part's answer with the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing {
+        actual: Option<String>,
+    },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "✔ pass"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "✖ fail (expected {expected}, got {actual})"),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "✖ fail (expected {expected}, got no answer)"),
+            Verdict::Missing {
+                actual: Some(actual),
+            } => {
+                write!(f, "? missing (no recorded answer, got {actual})")
+            }
+            Verdict::Missing { actual: None } => write!(f, "? missing"),
+        }
+    }
+}
+
+/// Answers are compared as [`Answer`]s, e.g. ASCII art matches regardless of trailing whitespace and
+/// matches the letters it draws.
+#[must_use]
+pub fn check(record: &PartRecord, expected: Option<&str>) -> Verdict {
+    let actual = record.answer.clone();
+
+    match expected {
+        None => Verdict::Missing { actual },
+        Some(expected)
+            if actual.as_deref().map(|a| Answer::from(a).recognized())
+                == Some(Answer::from(expected).recognized()) =>
+        {
+            Verdict::Pass
+        }
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+/// Runs the solutions of one or all days of `years` and compares their answers with
+/// `data/{year}/answers`. Exits with a non-zero status if any part does not match its recorded answer.
+pub fn handle(years: Years, day: Option<Day>, is_release: bool) {
+    let records = match (years, day) {
+        (Years::One(year), Some(day)) => {
+            let puzzle = match PuzzleId::checked(year, day) {
+                Ok(puzzle) => puzzle,
+                Err(e) => {
+                    eprintln!("Failed to verify day {day}: {e}.");
+                    process::exit(1);
+                }
+            };
+            if !Path::new(&get_path_for_bin(puzzle)).exists() {
+                eprintln!("Day {day} of {year} has not been scaffolded yet.");
+                process::exit(1);
+            }
+            child_commands::run_bin(&puzzle.to_string(), is_release, false, &[])
+        }
+        (Years::Every, Some(_)) => {
+            eprintln!("A single day can only be verified for one year, pass `--year`.");
+            process::exit(1);
+        }
+        (years, None) => {
+            let years = years.to_string();
+            child_commands::run_bin(
+                "aoc-all",
+                is_release,
+                false,
+                &["--year", &years],
+            )
+        }
+    };
+
+    let records = match records {
+        Ok(records) => records.collect_vec(),
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (i, (puzzle, day_records)) in records
+        .iter()
+        .chunk_by(|r| r.puzzle)
+        .into_iter()
+        .enumerate()
+    {
+        if i > 0 {
+            println!();
+        }
+
+        match years {
+            Years::One(_) => {
+                println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day)
+            }
+            Years::Every => println!(
+                "{ANSI_BOLD}{} Day {}{ANSI_RESET}",
+                puzzle.year, puzzle.day
+            ),
+        }
+        println!("------");
+
+        let expected = answers::read(puzzle).unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {e}", answers::get_path(puzzle));
+            answers::Answers::default()
+        });
+
+        // parse functions have no answer to verify.
+        for record in day_records.filter(|record| record.part != PARSE) {
+            let verdict = check(record, expected.get(record.part));
+
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing { .. } => missing += 1,
+            }
+
+            println!("Part {}: {verdict}", record.part);
+        }
+    }
+
+    println!("\n{ANSI_BOLD}{passed} passed, {failed} failed, {missing} missing{ANSI_RESET}");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check, Verdict};
+    use crate::template::runner::{BenchStats, PartRecord};
+    use crate::{day, PuzzleId};
+    use std::time::Duration;
+
+    fn record(answer: Option<&str>) -> PartRecord {
+        PartRecord::new(
+            PuzzleId::new(2025, day!(1)),
+            1,
+            answer.as_ref(),
+            BenchStats::single(Duration::ZERO),
+        )
+    }
+
+    #[test]
+    fn passes_matching_answer() {
+        assert_eq!(check(&record(Some("42")), Some("42")), Verdict::Pass);
+    }
+
+    #[test]
+    fn passes_equivalent_ascii_art() {
+        assert_eq!(
+            check(&record(Some("#. \n.#")), Some("#.\n.#\n")),
+            Verdict::Pass
+        );
+    }
+
+    #[test]
+    fn passes_letters_drawn_as_ascii_art() {
+        let art = "#..#.###\n#..#..#.\n####..#.\n#..#..#.\n#..#..#.\n#..#.###";
+        assert_eq!(check(&record(Some(art)), Some("HI")), Verdict::Pass);
+    }
+
+    #[test]
+    fn fails_different_or_missing_answer() {
+        assert_eq!(
+            check(&record(Some("41")), Some("42")),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: Some("41".into())
+            }
+        );
+        assert_eq!(
+            check(&record(None), Some("42")),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: None
+            }
+        );
+    }
+
+    #[test]
+    fn reports_unrecorded_answer() {
+        assert_eq!(
+            check(&record(Some("42")), None),
+            Verdict::Missing {
+                actual: Some("42".into())
+            }
+        );
+    }
+}
@@ -0,0 +1,334 @@
+/// Module that loads the project configuration from [`CONFIG_PATH`].
+/// Every setting is optional and defaults to the behavior of a template without configuration:
+///
+/// ```toml
+/// # the year commands work on unless `--year` or `AOC_YEAR` is given.
+/// year = 2025
+///
+/// # number of puzzles of each event, 25 unless listed here.
+/// [days]
+/// 2025 = 12
+///
+/// [paths]
+/// data = "data"          # year directories with inputs, examples, puzzles, answers and guesses
+/// bin = "src/bin"        # year directories with the solutions, see `[[bin]]` in Cargo.toml
+/// inputs = "inputs"
+/// examples = "examples"
+/// puzzles = "puzzles"
+/// templates = "templates" # user templates for `scaffold`
+///
+/// [scaffold]
+/// template = "default"   # unless `--template` is given
+/// answer = "u32"         # return type of the parts unless `--answer` is given
+///
+/// [readme]
+/// path = "README.md"
+/// marker = "<!--- benchmarking table --->"
+///
+/// [bench]
+/// target_ms = 1000       # approximate time spent benching a part
+/// min_samples = 10
+/// max_samples = 10000
+/// timeout_secs = 60      # unless `--timeout` is given, at least 1
+/// regression_threshold = 10.0
+///
+/// [submit]
+/// wait = false           # wait for cooldowns as if `--wait` was given
+/// guard = true           # refuse answers ruled out by previous guesses
+/// ```
+///
+/// This module is also compiled into the build script, which reads the days and paths, so it must
+/// not depend on the rest of the crate.
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<u16>,
+    /// Number of days of each event by year, see [`Config::event_days`].
+    days: BTreeMap<String, u8>,
+    pub paths: Paths,
+    pub scaffold: Scaffold,
+    pub readme: Readme,
+    pub bench: Bench,
+    pub submit: Submit,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub data: PathBuf,
+    pub bin: PathBuf,
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    pub templates: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scaffold {
+    pub template: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Readme {
+    pub path: PathBuf,
+    pub marker: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Bench {
+    pub target_ms: u64,
+    pub min_samples: u32,
+    pub max_samples: u32,
+    pub timeout_secs: u64,
+    pub regression_threshold: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Submit {
+    pub wait: bool,
+    pub guard: bool,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            data: "data".into(),
+            bin: ["src", "bin"].iter().collect(),
+            inputs: "inputs".into(),
+            examples: "examples".into(),
+            puzzles: "puzzles".into(),
+            templates: "templates".into(),
+        }
+    }
+}
+
+impl Default for Scaffold {
+    fn default() -> Self {
+        Self {
+            template: "default".into(),
+            answer: "u32".into(),
+        }
+    }
+}
+
+impl Default for Readme {
+    fn default() -> Self {
+        Self {
+            path: "README.md".into(),
+            marker: "<!--- benchmarking table --->".into(),
+        }
+    }
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            target_ms: 1000,
+            min_samples: 10,
+            max_samples: 10000,
+            timeout_secs: 60,
+            regression_threshold: 10.0,
+        }
+    }
+}
+
+impl Default for Submit {
+    fn default() -> Self {
+        Self {
+            wait: false,
+            guard: true,
+        }
+    }
+}
+
+impl Config {
+    /// Parses and validates a configuration.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let config: Config =
+            toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))?;
+
+        for (year, days) in &config.days {
+            if year.parse::<u16>().is_err() {
+                return Err(Error::Parser(format!(
+                    "invalid year `{year}` in `days`"
+                )));
+            }
+
+            if !(1..=25).contains(days) {
+                return Err(Error::Parser(format!(
+                    "days of {year} must be a number between 1 and 25"
+                )));
+            }
+        }
+
+        if config.bench.min_samples == 0
+            || config.bench.min_samples > config.bench.max_samples
+        {
+            return Err(Error::Parser(
+                "`bench.min_samples` must be between 1 and `bench.max_samples`"
+                    .into(),
+            ));
+        }
+
+        if config.bench.timeout_secs == 0 {
+            return Err(Error::Parser(
+                "`bench.timeout_secs` must be at least 1".into(),
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the configuration at `path`, or the defaults if there is no such file.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    /// The configured number of days of each event, by year.
+    pub fn event_days(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.days
+            .iter()
+            .filter_map(|(year, days)| Some((year.parse().ok()?, *days)))
+    }
+}
+
+impl Paths {
+    /// The directory of a year's data, e.g. `data/2025`.
+    #[must_use]
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.data.join(year.to_string())
+    }
+
+    /// The directory of a year's solutions, e.g. `src/bin/2025`.
+    #[must_use]
+    pub fn bin_dir(&self, year: u16) -> PathBuf {
+        self.bin.join(year.to_string())
+    }
+
+    /// Resolves a data folder of a year by its default name, e.g. `inputs`, to its configured path.
+    #[must_use]
+    pub fn folder(&self, year: u16, name: &str) -> PathBuf {
+        let name = match name {
+            "inputs" => &self.inputs,
+            "examples" => &self.examples,
+            "puzzles" => &self.puzzles,
+            name => name,
+        };
+
+        self.year_dir(year).join(name)
+    }
+}
+
+impl Bench {
+    #[must_use]
+    pub fn target(&self) -> Duration {
+        Duration::from_millis(self.target_ms)
+    }
+
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid {CONFIG_PATH}: {e}"),
+            Error::IO(e) => write!(f, "failed to read {CONFIG_PATH}: {e}"),
+        }
+    }
+}
+
+/// Returns the configuration of the project, loading [`CONFIG_PATH`] on first use.
+/// Exits if the file is invalid.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+
+    CONFIG.get_or_init(|| {
+        Config::read(Path::new(CONFIG_PATH)).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    })
+}
+
+// also included by `build.rs`, where the tests would be unused.
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Config;
+    use std::path::PathBuf;
+
+    #[test]
+    fn defaults_without_settings() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config, Config::default());
+        assert_eq!(
+            config.paths.folder(2025, "inputs"),
+            PathBuf::from("data/2025/inputs")
+        );
+        assert_eq!(config.bench.timeout_secs, 60);
+        assert!(config.submit.guard);
+    }
+
+    #[test]
+    fn parses_settings() {
+        let config = Config::parse(
+            "year = 2024\n[days]\n2025 = 12\n[paths]\ndata = \"aoc\"\ninputs = \"in\"\n[submit]\nwait = true",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.event_days().collect::<Vec<_>>(), vec![(2025, 12)]);
+        assert_eq!(
+            config.paths.folder(2024, "inputs"),
+            PathBuf::from("aoc/2024/in")
+        );
+        assert_eq!(
+            config.paths.folder(2024, "answers"),
+            PathBuf::from("aoc/2024/answers")
+        );
+        assert!(config.submit.wait);
+        assert!(config.submit.guard);
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(Config::parse("[days]\n2025 = 26").is_err());
+        assert!(Config::parse("[days]\nnext = 12").is_err());
+        assert!(Config::parse("[bench]\ntimeout_secs = 0").is_err());
+        assert!(Config::parse("[bench]\nmin_samples = 0").is_err());
+        assert!(Config::parse("[paths]\ninput = \"in\"").is_err());
+    }
+}
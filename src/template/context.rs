@@ -0,0 +1,296 @@
+/// Module that describes what a solution part is run against.
+/// Parameters that differ between the examples and the real input (e.g. a number of steps) are
+/// declared in `data/{year}/examples/{day}.toml` and read through the [`Context`] instead of `cfg` flags.
+use std::{env, fmt::Debug, fs, io, path::PathBuf, process, str::FromStr};
+
+use toml::{Table, Value};
+
+use crate::template::{config, examples::Examples};
+use crate::PuzzleId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Real,
+    Example,
+}
+
+/// Passed to parts declared as `fn(&str, &Context) -> Option<T>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Context {
+    pub puzzle: PuzzleId,
+    pub kind: InputKind,
+    params: Table,
+}
+
+impl Context {
+    #[must_use]
+    pub fn new(puzzle: PuzzleId, kind: InputKind, params: Table) -> Self {
+        Self {
+            puzzle,
+            kind,
+            params,
+        }
+    }
+
+    /// The context of the real input, with the top-level parameters of the examples file.
+    #[must_use]
+    pub fn real(puzzle: PuzzleId) -> Self {
+        Self::new(puzzle, InputKind::Real, read_examples(puzzle).params)
+    }
+
+    /// The context of the `index`-th example declared in the examples file, with its parameters
+    /// overriding the top-level ones.
+    ///
+    /// # Panics
+    /// If the examples file does not declare enough examples.
+    #[must_use]
+    pub fn example(puzzle: PuzzleId, index: usize) -> Self {
+        let examples = read_examples(puzzle);
+        let mut params = examples.params;
+        let example = examples
+            .examples
+            .into_iter()
+            .nth(index)
+            .expect("example is not declared in examples file");
+
+        params.extend(example.params);
+        Self::new(puzzle, InputKind::Example, params)
+    }
+
+    #[must_use]
+    pub fn is_example(&self) -> bool {
+        self.kind == InputKind::Example
+    }
+
+    /// Reads a named parameter.
+    ///
+    /// # Panics
+    /// If the parameter is not declared or cannot be parsed, since the solution cannot be run
+    /// without it.
+    #[must_use]
+    pub fn param<T: FromStr>(&self, name: &str) -> T
+    where
+        T::Err: Debug,
+    {
+        let value = self.params.get(name).unwrap_or_else(|| {
+            panic!(
+                "parameter `{name}` is not declared in \"{}\"",
+                get_path(self.puzzle)
+            )
+        });
+
+        let value = match value {
+            Value::String(s) => s.clone(),
+            value => value.to_string(),
+        };
+
+        value.parse().unwrap_or_else(|e| {
+            panic!(
+                "parameter `{name}` has an unexpected value `{value}`: {e:?}"
+            )
+        })
+    }
+}
+
+/// The input a solution binary runs on, selected by `--input <path>`, `--example [K]` or `--stdin`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The puzzle input in `data/{year}/inputs`.
+    #[default]
+    Real,
+    /// The `K`-th example file, `NN.txt` for the first and `NN-K.txt` for the others.
+    Example(u32),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input source passed to a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let value = |flag: &str| {
+            args.iter().position(|x| x == flag).map(|i| args.get(i + 1))
+        };
+
+        if let Some(path) = value("--input") {
+            match path {
+                Some(path) => Self::File(path.into()),
+                None => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --input path/to/input.txt");
+                    process::exit(1);
+                }
+            }
+        } else if let Some(number) = value("--example") {
+            match number.map(|x| x.parse()) {
+                Some(Ok(number)) if number > 0 => Self::Example(number),
+                _ => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --example 2");
+                    process::exit(1);
+                }
+            }
+        } else if args.iter().any(|x| x == "--stdin") {
+            Self::Stdin
+        } else {
+            Self::Real
+        }
+    }
+
+    /// The arguments that select this source in a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Real => vec![],
+            Self::Example(number) => {
+                vec!["--example".into(), number.to_string()]
+            }
+            Self::File(path) => {
+                vec!["--input".into(), path.display().to_string()]
+            }
+            Self::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    /// The name of an example file, relative to the examples directory.
+    fn example_file(puzzle: PuzzleId, number: u32) -> String {
+        match number {
+            1 => format!("{}.txt", puzzle.day),
+            number => format!("{}-{number}.txt", puzzle.day),
+        }
+    }
+
+    /// Reads the input, exiting if it cannot be read.
+    #[must_use]
+    pub fn read(&self, puzzle: PuzzleId) -> String {
+        let (name, input) = match self {
+            Self::Real => {
+                let path = config::get()
+                    .paths
+                    .folder(puzzle.year, "inputs")
+                    .join(format!("{}.txt", puzzle.day));
+                (path.display().to_string(), fs::read_to_string(path))
+            }
+            Self::Example(number) => {
+                let path = config::get()
+                    .paths
+                    .folder(puzzle.year, "examples")
+                    .join(Self::example_file(puzzle, *number));
+                (path.display().to_string(), fs::read_to_string(path))
+            }
+            Self::File(path) => {
+                (path.display().to_string(), fs::read_to_string(path))
+            }
+            Self::Stdin => ("stdin".into(), io::read_to_string(io::stdin())),
+        };
+
+        input.unwrap_or_else(|e| {
+            eprintln!("Failed to read input from \"{name}\": {e}");
+            process::exit(1);
+        })
+    }
+
+    /// The context of the input. An example gets the parameters of the first entry of the examples file
+    /// that declares its file, other inputs get the top-level parameters like the real one.
+    #[must_use]
+    pub fn context(&self, puzzle: PuzzleId) -> Context {
+        let Self::Example(number) = self else {
+            return Context::real(puzzle);
+        };
+
+        let file = Self::example_file(puzzle, *number);
+        let examples = read_examples(puzzle);
+        let mut params = examples.params;
+
+        if let Some(example) =
+            examples.examples.into_iter().find(|e| e.file == file)
+        {
+            params.extend(example.params);
+        }
+
+        Context::new(puzzle, InputKind::Example, params)
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    let path = config::get()
+        .paths
+        .folder(puzzle.year, "examples")
+        .join(format!("{}.toml", puzzle.day));
+    path.display().to_string()
+}
+
+/// Reads the examples file of a day. A missing file declares no examples and no parameters.
+///
+/// # Panics
+/// If the examples file is not valid.
+#[must_use]
+pub fn read_examples(puzzle: PuzzleId) -> Examples {
+    let filepath = env::current_dir().unwrap().join(get_path(puzzle));
+
+    match fs::read_to_string(filepath) {
+        Ok(s) => Examples::parse(&s).expect("could not parse examples file"),
+        Err(_) => Examples::default(),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Context, InputKind, InputSource};
+    use crate::{day, PuzzleId};
+    use toml::Table;
+
+    #[test]
+    fn reads_params() {
+        let params: Table =
+            toml::from_str("steps = 64\nname = \"abc\"").unwrap();
+        let context =
+            Context::new(PuzzleId::new(2025, day!(1)), InputKind::Real, params);
+
+        assert_eq!(context.param::<usize>("steps"), 64);
+        assert_eq!(context.param::<String>("name"), "abc");
+        assert!(!context.is_example());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_missing_param() {
+        let context = Context::new(
+            PuzzleId::new(2025, day!(1)),
+            InputKind::Example,
+            Table::new(),
+        );
+        let _: usize = context.param("steps");
+    }
+
+    #[test]
+    fn forwards_input_sources() {
+        assert!(InputSource::Real.to_args().is_empty());
+        assert_eq!(InputSource::Example(2).to_args(), ["--example", "2"]);
+        assert_eq!(
+            InputSource::File("stress.txt".into()).to_args(),
+            ["--input", "stress.txt"]
+        );
+        assert_eq!(InputSource::Stdin.to_args(), ["--stdin"]);
+        assert_eq!(
+            InputSource::example_file(PuzzleId::new(2025, day!(11)), 1),
+            "11.txt"
+        );
+        assert_eq!(
+            InputSource::example_file(PuzzleId::new(2025, day!(11)), 2),
+            "11-2.txt"
+        );
+    }
+
+    #[test]
+    fn reads_example_params() {
+        let puzzle = PuzzleId::new(2025, day!(8));
+        let example = InputSource::Example(1).context(puzzle);
+        let stdin = InputSource::Stdin.context(puzzle);
+
+        assert!(example.is_example());
+        assert_eq!(example.param::<usize>("connections"), 10);
+        assert!(!stdin.is_example());
+        assert_eq!(stdin.param::<usize>("connections"), 1000);
+    }
+}
@@ -0,0 +1,143 @@
+/// Module that remembers submission cooldowns across invocations.
+/// When the site rate-limits a submission, the deadline is stored in `cooldowns.json` in the data directory
+/// so that later submissions of the same part can wait for it or refuse early.
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::config;
+use crate::PuzzleId;
+
+/// The cooldowns file, e.g. `data/cooldowns.json`.
+#[must_use]
+pub fn get_path() -> PathBuf {
+    config::get().paths.data.join("cooldowns.json")
+}
+
+/// Cooldown deadlines in seconds since the unix epoch, keyed by `{year}-{day}-{part}`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Cooldowns(BTreeMap<String, u64>);
+
+impl Cooldowns {
+    /// The time left until a part may be submitted again, if any.
+    #[must_use]
+    pub fn remaining(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        now: u64,
+    ) -> Option<Duration> {
+        let deadline = *self.0.get(&key(puzzle, part))?;
+        (deadline > now).then(|| Duration::from_secs(deadline - now))
+    }
+
+    /// Sets the deadline of a part, dropping deadlines that already passed.
+    pub fn set(&mut self, puzzle: PuzzleId, part: u8, deadline: u64, now: u64) {
+        self.0.retain(|_, d| *d > now);
+        self.0.insert(key(puzzle, part), deadline);
+    }
+
+    fn parse(s: &str) -> Self {
+        Self(serde_json::from_str(s).unwrap_or_default())
+    }
+}
+
+fn key(puzzle: PuzzleId, part: u8) -> String {
+    format!("{puzzle}-{part}")
+}
+
+/// Seconds since the unix epoch.
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Reads the stored cooldowns. A missing file means no cooldowns.
+pub fn read() -> io::Result<Cooldowns> {
+    match fs::read_to_string(get_path()) {
+        Ok(s) => Ok(Cooldowns::parse(&s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Ok(Cooldowns::default())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Stores that a part may not be submitted for `wait`.
+pub fn record(puzzle: PuzzleId, part: u8, wait: Duration) -> io::Result<()> {
+    let now = now();
+    let mut cooldowns = read()?;
+    cooldowns.set(puzzle, part, now + wait.as_secs(), now);
+
+    let path = get_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&cooldowns.0).unwrap())
+}
+
+/// Formats a duration as e.g. `4m 52s`.
+#[must_use]
+pub fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{format_wait, Cooldowns};
+    use crate::{day, PuzzleId};
+    use std::time::Duration;
+
+    #[test]
+    fn tracks_remaining_time() {
+        let mut cooldowns = Cooldowns::default();
+        cooldowns.set(PuzzleId::new(2025, day!(1)), 1, 160, 100);
+
+        assert_eq!(
+            cooldowns.remaining(PuzzleId::new(2025, day!(1)), 1, 100),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            cooldowns.remaining(PuzzleId::new(2025, day!(1)), 1, 160),
+            None
+        );
+        assert_eq!(
+            cooldowns.remaining(PuzzleId::new(2025, day!(1)), 2, 100),
+            None
+        );
+        assert_eq!(
+            cooldowns.remaining(PuzzleId::new(2025, day!(2)), 1, 100),
+            None
+        );
+    }
+
+    #[test]
+    fn drops_expired_cooldowns() {
+        let mut cooldowns = Cooldowns::default();
+        cooldowns.set(PuzzleId::new(2025, day!(1)), 1, 160, 100);
+        cooldowns.set(PuzzleId::new(2025, day!(1)), 2, 300, 200);
+
+        assert_eq!(cooldowns.0.len(), 1);
+        assert_eq!(
+            Cooldowns::parse(&serde_json::to_string(&cooldowns.0).unwrap()),
+            cooldowns
+        );
+        assert_eq!(Cooldowns::parse("garbage"), Cooldowns::default());
+    }
+
+    #[test]
+    fn formats_wait() {
+        assert_eq!(format_wait(Duration::from_secs(45)), "45s");
+        assert_eq!(format_wait(Duration::from_secs(292)), "4m 52s");
+    }
+}
@@ -0,0 +1,172 @@
+/// Module that extracts the examples of a puzzle from its markdown description.
+/// An example is the first code block following a paragraph that mentions "for example". Its expected
+/// answers are guessed from the last emphasized code (e.g. `` `*42*` ``) that follows it in each part.
+/// These are heuristics, the extracted answers should be checked before relying on them.
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// An example input with the answers guessed from the description.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+enum State {
+    Text,
+    Example(String),
+    Block,
+}
+
+/// Extracts the examples of a description in order of appearance.
+/// An example repeated in part two is only returned once.
+#[must_use]
+pub fn extract_examples(description: &str) -> Vec<Example> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+
+    let emphasized = RE
+        .get_or_init(|| Regex::new(r"`\*([^*`]+)\*`|\*`([^*`]+)`\*").unwrap());
+
+    let mut examples: Vec<Example> = vec![];
+    // the latest example and the latest emphasized value after it, per part.
+    let mut answers: [Option<(usize, String)>; 2] = [None, None];
+    let mut current: Option<usize> = None;
+    let mut part = 0;
+    let mut wants_example = false;
+    let mut state = State::Text;
+
+    for line in description.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        state = match (state, is_fence) {
+            (State::Text, true) if wants_example => {
+                wants_example = false;
+                State::Example(String::new())
+            }
+            (State::Text, true) => State::Block,
+            (State::Example(input), true) => {
+                current = Some(
+                    match examples.iter().position(|e| e.input == input) {
+                        Some(i) => i,
+                        None => {
+                            examples.push(Example {
+                                input,
+                                ..Example::default()
+                            });
+                            examples.len() - 1
+                        }
+                    },
+                );
+                State::Text
+            }
+            (State::Example(mut input), false) => {
+                input.push_str(line);
+                input.push('\n');
+                State::Example(input)
+            }
+            (State::Block, true) => State::Text,
+            (State::Block, false) => State::Block,
+            (State::Text, false) => {
+                if line.contains("--- Part Two ---") {
+                    part = 1;
+                }
+
+                if line.to_lowercase().contains("for example") {
+                    wants_example = true;
+                }
+
+                let value = emphasized
+                    .captures_iter(line)
+                    .last()
+                    .and_then(|c| c.get(1).or_else(|| c.get(2)));
+
+                if let (Some(example), Some(value)) = (current, value) {
+                    answers[part] = Some((example, value.as_str().to_string()));
+                }
+
+                State::Text
+            }
+        };
+    }
+
+    let [part_one, part_two] = answers;
+
+    if let Some((i, answer)) = part_one {
+        examples[i].part_one = Some(answer);
+    }
+
+    if let Some((i, answer)) = part_two {
+        examples[i].part_two = Some(answer);
+    }
+
+    examples
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{extract_examples, Example};
+
+    const DESCRIPTION: &str = r#"## --- Day 1: Test ---
+
+The elves have a new list, blocks that are not introduced as an example are skipped:
+
+```
+unrelated
+```
+
+So, for example, take this list:
+
+```
+1
+2
+```
+
+After the first step, the sum is `*1*`, and the final sum is `*3*`.
+
+## --- Part Two ---
+
+In the example above, the product is `*2*`. For example, take another list:
+
+```
+3
+4
+```
+
+The product of this one is *`12`*.
+"#;
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        assert_eq!(
+            extract_examples(DESCRIPTION),
+            vec![
+                Example {
+                    input: "1\n2\n".into(),
+                    part_one: Some("3".into()),
+                    part_two: None,
+                },
+                Example {
+                    input: "3\n4\n".into(),
+                    part_one: None,
+                    part_two: Some("12".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_repeated_examples() {
+        let description = "For example:\n```\n1\n```\nIt is `*1*`.\n--- Part Two ---\nFor example:\n```\n1\n```\nNow it is `*2*`.";
+
+        assert_eq!(
+            extract_examples(description),
+            vec![Example {
+                input: "1\n".into(),
+                part_one: Some("1".into()),
+                part_two: Some("2".into()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+/// Module that describes the examples of a day and their expected answers.
+/// Examples are declared in `data/{year}/examples/{day}.toml`:
+///
+/// ```toml
+/// # parameters that apply to the real input and every example unless overridden.
+/// [params]
+/// connections = 1000
+///
+/// [[example]]
+/// file = "08.txt"
+/// part_one = 40
+/// params = { connections = 10 }
+/// ```
+///
+/// This module is also compiled into the build script, which generates one test per example and
+/// part, so it must not depend on the rest of the crate.
+use serde::Deserialize;
+use toml::Table;
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Examples {
+    #[serde(default)]
+    pub params: Table,
+    #[serde(rename = "example", default)]
+    pub examples: Vec<Example>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Example {
+    /// File name relative to `data/{year}/examples`.
+    pub file: String,
+    #[serde(default, deserialize_with = "answer")]
+    pub part_one: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    pub part_two: Option<String>,
+    /// Overrides of the top-level parameters for this example.
+    #[serde(default)]
+    pub params: Table,
+    /// Why the tests of this example are ignored, e.g. when a solution only handles the real input.
+    pub ignore: Option<String>,
+}
+
+impl Examples {
+    pub fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+}
+
+impl Example {
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Answers may be written as integers or strings.
+fn answer<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Integer(i64),
+        String(String),
+    }
+
+    Ok(Some(match Value::deserialize(deserializer)? {
+        Value::Integer(x) => x.to_string(),
+        Value::String(s) => s,
+    }))
+}
+
+// also included by `build.rs`, where the tests would be unused.
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Examples;
+
+    #[test]
+    fn parses_examples() {
+        let examples = Examples::parse(
+            "[[example]]\nfile = \"11.txt\"\npart_one = 5\n\n[[example]]\nfile = \"11-2.txt\"\npart_two = \"abc\"\nignore = \"slow\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(examples.examples.len(), 2);
+        assert_eq!(examples.examples[0].file, "11.txt");
+        assert_eq!(examples.examples[0].expected(1), Some("5"));
+        assert_eq!(examples.examples[0].expected(2), None);
+        assert_eq!(examples.examples[1].expected(2), Some("abc"));
+        assert_eq!(examples.examples[0].ignore, None);
+        assert_eq!(examples.examples[1].ignore.as_deref(), Some("slow"));
+    }
+
+    #[test]
+    fn parses_params() {
+        let examples = Examples::parse(
+            "[params]\nconnections = 1000\n\n[[example]]\nfile = \"08.txt\"\nparams = { connections = 10 }\n",
+        )
+        .unwrap();
+
+        assert_eq!(examples.params["connections"].as_integer(), Some(1000));
+        assert_eq!(
+            examples.examples[0].params["connections"].as_integer(),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn parses_empty_examples() {
+        assert_eq!(Examples::parse("").unwrap(), Examples::default());
+    }
+}
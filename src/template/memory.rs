@@ -0,0 +1,186 @@
+/// Module that measures heap usage of solution parts.
+/// Counting is only active with the `memory` feature, which installs [`CountingAllocator`] as the
+/// global allocator. Allocations of all threads are counted, so parts running concurrently (e.g.
+/// `all --parallel`) are not measured accurately.
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Peak heap usage above the usage when the measurement started.
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+impl MemoryStats {
+    /// The peak heap usage, e.g. `1.5 MiB`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format_bytes(self.peak_bytes)
+    }
+}
+
+/// A running measurement, see [`start`].
+pub struct Measurement {
+    #[allow(dead_code)]
+    base: usize,
+}
+
+impl Measurement {
+    #[must_use]
+    pub fn finish(self) -> MemoryStats {
+        counting::finish(self.base)
+    }
+}
+
+/// Starts measuring heap usage, returns [`None`] if the `memory` feature is disabled.
+#[must_use]
+pub fn start() -> Option<Measurement> {
+    counting::start().map(|base| Measurement { base })
+}
+
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "memory")]
+pub use counting::CountingAllocator;
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(feature = "memory")]
+mod counting {
+    use super::MemoryStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Wraps the system allocator and keeps track of the current and peak heap usage.
+    pub struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn grow(size: usize) {
+            let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                Self::grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                Self::grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(
+            &self,
+            ptr: *mut u8,
+            layout: Layout,
+            new_size: usize,
+        ) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                if new_size > layout.size() {
+                    Self::grow(new_size - layout.size());
+                } else {
+                    CURRENT
+                        .fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn start() -> Option<usize> {
+        let base = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(base, Ordering::Relaxed);
+        ALLOCATIONS.store(0, Ordering::Relaxed);
+        Some(base)
+    }
+
+    pub fn finish(base: usize) -> MemoryStats {
+        MemoryStats {
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(not(feature = "memory"))]
+mod counting {
+    use super::MemoryStats;
+
+    pub fn start() -> Option<usize> {
+        None
+    }
+
+    pub fn finish(_: usize) -> MemoryStats {
+        MemoryStats {
+            peak_bytes: 0,
+            allocations: 0,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn counts_allocations() {
+        let measurement = super::start().unwrap();
+        let data = vec![0_u8; 1 << 20];
+        let stats = measurement.finish();
+        drop(data);
+
+        assert!(stats.peak_bytes >= 1 << 20);
+        assert!(stats.allocations >= 1);
+    }
+}
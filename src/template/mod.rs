@@ -1,46 +1,225 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::{env, fs, path::PathBuf};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod bench_history;
 pub mod commands;
+pub mod config;
+pub mod context;
+pub mod cooldown;
+pub mod description;
+pub mod examples;
+pub mod memory;
+pub mod module_template;
+pub mod provider;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod submission;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-pub fn read_extra_example_file(day: Day, number: u32) -> String {
+/// The year set by `AOC_YEAR` or else in the config, used when no `--year` is passed.
+#[must_use]
+pub fn get_year() -> Option<u16> {
+    match env::var("AOC_YEAR") {
+        Ok(year) => year.parse().ok(),
+        Err(_) => config::get().year,
+    }
+}
+
+/// The data directory of a year, e.g. `data/2025`.
+#[must_use]
+pub fn get_data_dir(year: u16) -> PathBuf {
+    config::get().paths.year_dir(year)
+}
+
+pub fn read_extra_example_file(puzzle: PuzzleId, number: u32) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join("examples")
-        .join(format!("{day}-{number}.txt"));
+        .join(config::get().paths.folder(puzzle.year, "examples"))
+        .join(format!("{}-{number}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
+/// Reads an example file of a year by name, e.g. `11-2.txt`.
+#[must_use]
+pub fn read_example_file(year: u16, name: &str) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(config::get().paths.folder(year, "examples"))
+        .join(name);
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open example file")
+}
+
 /// Helper function that reads a text file to a string.
+/// `folder` is the default name of a data folder, e.g. `inputs`, and resolved through the config.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(config::get().paths.folder(puzzle.year, folder))
+        .join(format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
+/// Only the part passed with `--part`, if any, is run.
+///
+/// With a parse function, e.g. `solution!(2025, 8, parse)`, the input is parsed once by
+/// `parse(&str) -> Parsed` and the parts take `&Parsed` instead of `&str`. Parsing is timed separately
+/// from the parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@puzzle $year:expr, $day:expr) => {
         /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        const DAY: advent_of_code::Day = advent_of_code::day!($year, $day);
+
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new($year, DAY);
+    };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@puzzle $year, $day);
+
+        /// Registers the day for in-process runs by the `aoc-all` binary.
+        pub const SOLUTION: advent_of_code::template::runner::Solution =
+            advent_of_code::template::runner::Solution {
+                puzzle: PUZZLE,
+                run: || {
+                    use advent_of_code::template::runner::*;
+                    // parts run on their own threads, leak the input to share it with them.
+                    let input: &'static str =
+                        advent_of_code::template::read_file("inputs", PUZZLE).leak();
+                    let context = std::sync::Arc::new(
+                        advent_of_code::template::context::Context::real(PUZZLE),
+                    );
+                    let mut records = vec![];
+                    if is_selected(1) {
+                        records.push(solve_part(part_one, input, &context, PUZZLE, 1));
+                    }
+                    if is_selected(2) {
+                        records.push(solve_part(part_two, input, &context, PUZZLE, 2));
+                    }
+                    records
+                },
+            };
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let source = advent_of_code::template::context::InputSource::from_args();
+            // parts run on their own threads, leak the input to share it with them.
+            let input: &'static str = source.read(PUZZLE).leak();
+            let context = std::sync::Arc::new(source.context(PUZZLE));
+            if is_selected(1) {
+                run_part(part_one, input, &context, PUZZLE, 1);
+            }
+            if is_selected(2) {
+                run_part(part_two, input, &context, PUZZLE, 2);
+            }
+        }
+
+        /// Solves a part on an example, used by the tests of `example_tests!`.
+        #[cfg(test)]
+        #[allow(dead_code)]
+        fn solve_example(
+            part: u8,
+            input: &str,
+            context: &advent_of_code::template::context::Context,
+        ) -> Option<advent_of_code::Answer> {
+            use advent_of_code::template::runner::Part;
+            match part {
+                1 => part_one.solve(input, context).map(Into::into),
+                _ => part_two.solve(input, context).map(Into::into),
+            }
+        }
+    };
+    ($year:expr, $day:expr, $parse:path) => {
+        advent_of_code::solution!(@puzzle $year, $day);
+
+        /// Registers the day for in-process runs by the `aoc-all` binary.
+        pub const SOLUTION: advent_of_code::template::runner::Solution =
+            advent_of_code::template::runner::Solution {
+                puzzle: PUZZLE,
+                run: || {
+                    use advent_of_code::template::runner::*;
+                    let input: &'static str =
+                        advent_of_code::template::read_file("inputs", PUZZLE).leak();
+                    let context = std::sync::Arc::new(
+                        advent_of_code::template::context::Context::real(PUZZLE),
+                    );
+                    let (record, parsed) = solve_parse($parse, input, PUZZLE);
+                    let mut records = vec![record];
+                    if let Some(parsed) = parsed {
+                        if is_selected(1) {
+                            records.push(solve_part(part_one, parsed, &context, PUZZLE, 1));
+                        }
+                        if is_selected(2) {
+                            records.push(solve_part(part_two, parsed, &context, PUZZLE, 2));
+                        }
+                    }
+                    records
+                },
+            };
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let source = advent_of_code::template::context::InputSource::from_args();
+            // the parsed input may borrow from the input, leak it to share both with the parts.
+            let input: &'static str = source.read(PUZZLE).leak();
+            let context = std::sync::Arc::new(source.context(PUZZLE));
+            let Some(parsed) = run_parse($parse, input, PUZZLE) else {
+                return;
+            };
+            if is_selected(1) {
+                run_part(part_one, parsed, &context, PUZZLE, 1);
+            }
+            if is_selected(2) {
+                run_part(part_two, parsed, &context, PUZZLE, 2);
+            }
+        }
+
+        /// Solves a part on an example, used by the tests of `example_tests!`.
+        #[cfg(test)]
+        #[allow(dead_code)]
+        fn solve_example(
+            part: u8,
+            input: &str,
+            context: &advent_of_code::template::context::Context,
+        ) -> Option<advent_of_code::Answer> {
+            use advent_of_code::template::runner::Part;
+            let parsed = $parse(input);
+            match part {
+                1 => part_one.solve(&parsed, context).map(Into::into),
+                _ => part_two.solve(&parsed, context).map(Into::into),
+            }
+        }
+    };
+}
+
+/// Generates one test per example and part declared in `data/{year}/examples/{day}.toml`.
+/// Each test runs the part against the example file and compares its answer with the expected one.
+#[macro_export]
+macro_rules! example_tests {
+    ($year:literal, $day:literal) => {
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/example_tests/",
+                $year,
+                "/",
+                $day,
+                ".rs"
+            ));
         }
     };
 }
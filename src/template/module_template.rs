@@ -0,0 +1,193 @@
+/// Module that renders the solution module created by `scaffold`.
+/// Templates are looked up by name in the templates directory of the project first (`templates/{name}.rs`
+/// by default), then among the built-in variants: `default`, `grid` and `nom`.
+///
+/// The following placeholders are substituted:
+///  - `YEAR_NUMBER`: the year, e.g. `2025`.
+///  - `DAY_NUMBER`: the day, e.g. `1`.
+///  - `DAY_PADDED`: the day as two digits, e.g. `01`.
+///  - `PUZZLE_TITLE`: the title from the puzzle description, e.g. `Day 1: Secret Entrance`, or `Day 1`
+///    if the description was not downloaded yet.
+///  - `ANSWER_TYPE`: the return type of the parts, e.g. `u32`.
+use std::{fmt::Display, fs, io};
+
+use crate::template::config;
+use crate::PuzzleId;
+
+pub const DEFAULT_TEMPLATE: &str = r#"//! PUZZLE_TITLE
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
+    None
+}
+
+advent_of_code::example_tests!(YEAR_NUMBER, DAY_NUMBER);
+"#;
+
+pub const GRID_TEMPLATE: &str = r#"//! PUZZLE_TITLE
+use advent_of_code::Grid;
+
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
+
+fn parse(input: &str) -> Grid {
+    Grid::parse_lines(input)
+}
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+    let _grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
+    let _grid = parse(input);
+    None
+}
+
+advent_of_code::example_tests!(YEAR_NUMBER, DAY_NUMBER);
+"#;
+
+pub const NOM_TEMPLATE: &str = r#"//! PUZZLE_TITLE
+use nom::{
+    character::complete::{line_ending, u64},
+    multi::separated_list1,
+    IResult, Parser,
+};
+
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
+
+fn parse(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(line_ending, u64).parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+    let (_, _values) = parse(input).ok()?;
+    None
+}
+
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
+    let (_, _values) = parse(input).ok()?;
+    None
+}
+
+advent_of_code::example_tests!(YEAR_NUMBER, DAY_NUMBER);
+"#;
+
+#[derive(Debug)]
+pub enum Error {
+    Unknown(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unknown(name) => write!(
+                f,
+                "unknown template \"{name}\", expecting one of the built-in templates (default, grid, nom) or a file in \"{}\"",
+                config::get().paths.templates.display()
+            ),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Returns the template called `name`, preferring a user template over a built-in one.
+pub fn load(name: &str) -> Result<String, Error> {
+    let path = config::get().paths.templates.join(format!("{name}.rs"));
+
+    match fs::read_to_string(path) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+        Err(_) => {}
+    }
+
+    match name {
+        "default" => Ok(DEFAULT_TEMPLATE.into()),
+        "grid" => Ok(GRID_TEMPLATE.into()),
+        "nom" => Ok(NOM_TEMPLATE.into()),
+        name => Err(Error::Unknown(name.into())),
+    }
+}
+
+/// Substitutes the placeholders of a template.
+#[must_use]
+pub fn render(
+    template: &str,
+    puzzle: PuzzleId,
+    title: &str,
+    answer_type: &str,
+) -> String {
+    template
+        .replace("YEAR_NUMBER", &puzzle.year.to_string())
+        .replace("DAY_NUMBER", &puzzle.day.into_inner().to_string())
+        .replace("DAY_PADDED", &puzzle.day.to_string())
+        .replace("PUZZLE_TITLE", title)
+        .replace("ANSWER_TYPE", answer_type)
+}
+
+/// Extracts the title from a puzzle description, e.g. `Day 1: Secret Entrance` from the
+/// `--- Day 1: Secret Entrance ---` heading.
+#[must_use]
+pub fn parse_title(description: &str) -> Option<String> {
+    description.lines().find_map(|line| {
+        let (_, rest) = line.split_once("--- Day ")?;
+        let (title, _) = rest.split_once(" ---")?;
+        Some(format!("Day {}", title.trim()))
+    })
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{load, parse_title, render, DEFAULT_TEMPLATE};
+    use crate::{day, PuzzleId};
+
+    #[test]
+    fn parses_titles() {
+        assert_eq!(
+            parse_title("\\--- Day 1: Secret Entrance ---\n----------\n")
+                .as_deref(),
+            Some("Day 1: Secret Entrance")
+        );
+        assert_eq!(
+            parse_title("## --- Day 12: Christmas Tree Farm ---\n\n")
+                .as_deref(),
+            Some("Day 12: Christmas Tree Farm")
+        );
+        assert_eq!(parse_title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let module = render(
+            DEFAULT_TEMPLATE,
+            PuzzleId::new(2025, day!(3)),
+            "Day 3: Lobby",
+            "u64",
+        );
+
+        assert!(module.starts_with(
+            "//! Day 3: Lobby\nadvent_of_code::solution!(2025, 3);"
+        ));
+        assert!(
+            module.contains("pub fn part_two(input: &str) -> Option<u64> {")
+        );
+        assert!(module.ends_with("advent_of_code::example_tests!(2025, 3);\n"));
+    }
+
+    #[test]
+    fn loads_built_in_templates() {
+        assert!(load("grid").unwrap().contains("Grid::parse_lines(input)"));
+        assert!(load("nom").unwrap().contains("IResult"));
+        assert!(load("missing").is_err());
+    }
+}
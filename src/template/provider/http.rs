@@ -0,0 +1,407 @@
+/// Provider that talks to the Advent of Code website directly.
+/// The session cookie is read from `AOC_SESSION` or, like aoc-cli does, from `~/.adventofcode.session`.
+/// The site can be swapped for a stand-in server by setting `AOC_BASE_URL`.
+/// Requests identify this client in their user agent, with the contact from `AOC_CONTACT` if it is set.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::{
+    get_input_path, get_puzzle_path, parse_response, Provider, ProviderError,
+};
+use crate::template::submission::SubmissionResult;
+use crate::PuzzleId;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const CLIENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// How long a request may take in total before it is given up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Http {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    user_agent: String,
+}
+
+/// The user agent of requests, e.g. `advent_of_code/0.9.2 (me@example.com)`, as the site asks automated
+/// tools to say who to contact about them.
+fn user_agent(contact: Option<&str>) -> String {
+    match contact.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(contact) => format!("{CLIENT} ({contact})"),
+        None => CLIENT.into(),
+    }
+}
+
+impl Http {
+    #[must_use]
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let config = ureq::Agent::config_builder()
+            .timeout_global(Some(TIMEOUT))
+            .build();
+
+        Self {
+            agent: config.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            user_agent: user_agent(None),
+        }
+    }
+
+    #[must_use]
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL")
+            .unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Self {
+            user_agent: user_agent(env::var("AOC_CONTACT").ok().as_deref()),
+            ..Self::new(&base_url, read_session())
+        }
+    }
+
+    /// Fetches the input of a puzzle.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, ProviderError> {
+        let url = format!("{}/input", self.day_url(puzzle));
+        self.fetch(self.agent.get(&url))
+    }
+
+    /// Fetches the description of a puzzle, converted to markdown.
+    pub fn description(
+        &self,
+        puzzle: PuzzleId,
+    ) -> Result<String, ProviderError> {
+        let html = self.fetch(self.agent.get(&self.day_url(puzzle)))?;
+        Ok(articles_to_markdown(&html))
+    }
+
+    /// Posts an answer and returns the response message, converted to markdown.
+    pub fn post_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ProviderError> {
+        let url = format!("{}/answer", self.day_url(puzzle));
+        let html = self
+            .with_headers(self.agent.post(&url))?
+            .send_form([
+                ("level", part.to_string().as_str()),
+                ("answer", answer),
+            ])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| ProviderError::Http(e.to_string()))?;
+        Ok(articles_to_markdown(&html))
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        let day = puzzle.day.into_inner();
+        format!("{}/{}/day/{day}", self.base_url, puzzle.year)
+    }
+
+    fn with_headers<B>(
+        &self,
+        request: ureq::RequestBuilder<B>,
+    ) -> Result<ureq::RequestBuilder<B>, ProviderError> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            ProviderError::Config(
+                "no session cookie found. Set AOC_SESSION or create \"~/.adventofcode.session\"."
+                    .into(),
+            )
+        })?;
+
+        Ok(request
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", &self.user_agent))
+    }
+
+    fn fetch(
+        &self,
+        request: ureq::RequestBuilder<ureq::typestate::WithoutBody>,
+    ) -> Result<String, ProviderError> {
+        self.with_headers(request)?
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| ProviderError::Http(e.to_string()))
+    }
+}
+
+impl Provider for Http {
+    fn check(&self) -> Result<(), ProviderError> {
+        self.with_headers(self.agent.get(&self.base_url))?;
+        Ok(())
+    }
+
+    fn download(&self, puzzle: PuzzleId) -> Result<(), ProviderError> {
+        let input_path = get_input_path(puzzle);
+        let puzzle_path = get_puzzle_path(puzzle);
+
+        for path in [&input_path, &puzzle_path] {
+            if let Some(dir) = Path::new(path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        fs::write(&input_path, self.input(puzzle)?)?;
+        fs::write(&puzzle_path, self.description(puzzle)?)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn read(&self, puzzle: PuzzleId) -> Result<(), ProviderError> {
+        println!("{}", self.description(puzzle)?);
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResult, ProviderError> {
+        let response = self.post_answer(puzzle, part, answer)?;
+        eprintln!("{response}");
+        parse_response(&response)
+    }
+}
+
+fn read_session() -> Option<String> {
+    let from_env = env::var("AOC_SESSION").ok();
+    let from_file = || {
+        let home =
+            env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+        fs::read_to_string(PathBuf::from(home).join(".adventofcode.session"))
+            .ok()
+    };
+
+    from_env
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+/// Converts the `<article>` elements of a page to markdown. Puzzle pages hold one article per part,
+/// answer pages a single one with the response.
+fn articles_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end];
+        let content = article.find('>').map_or("", |i| &article[i + 1..]);
+        articles.push(html_to_markdown(content));
+        rest = &rest[start + end..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Converts the small subset of HTML used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start]);
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("a", false) => {
+                links.push(
+                    attribute(tag, "href").unwrap_or_default().to_string(),
+                );
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest);
+    out.trim().to_string()
+}
+
+fn push_text(out: &mut String, text: &str) {
+    out.push_str(
+        &text
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    );
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{articles_to_markdown, user_agent, Http, CLIENT};
+    use crate::{day, PuzzleId};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves `body` once and returns the base url and a handle yielding the raw request.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) =
+                    line.to_lowercase().strip_prefix("content-length: ")
+                {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let html = concat!(
+            "<main><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>",
+            "<p>Find the <em>sum</em> of <code>a &lt; b</code>, see <a href=\"/about\">here</a>.</p>",
+            "<pre><code>1 2\n<em>3</em> 4\n</code></pre>",
+            "<ul><li>one</li><li>two</li></ul></article>",
+            "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>More.</p></article></main>",
+        );
+
+        assert_eq!(
+            articles_to_markdown(html),
+            concat!(
+                "## --- Day 1: Test ---\n\n",
+                "Find the *sum* of `a < b`, see [here](/about).\n\n",
+                "```\n1 2\n3 4\n```\n\n",
+                "- one\n- two\n\n",
+                "## --- Part Two ---\n\nMore."
+            )
+        );
+    }
+
+    #[test]
+    fn fetches_from_base_url() {
+        let (base_url, request) =
+            serve_once("<article><p>--- Day 1 ---</p></article>");
+        let http = Http::new(&base_url, Some("abc".into()));
+
+        assert_eq!(
+            http.description(PuzzleId::new(2025, day!(1))).unwrap(),
+            "--- Day 1 ---"
+        );
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/1 HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+        assert!(request.contains(&format!("user-agent: {CLIENT}\r\n")));
+    }
+
+    #[test]
+    fn names_contact_in_user_agent() {
+        assert_eq!(user_agent(None), CLIENT);
+        assert_eq!(user_agent(Some(" ")), CLIENT);
+        assert_eq!(
+            user_agent(Some("me@example.com")),
+            format!("{CLIENT} (me@example.com)")
+        );
+    }
+
+    #[test]
+    fn posts_answers() {
+        let (base_url, request) =
+            serve_once("<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>");
+        let http = Http::new(&base_url, Some("abc".into()));
+
+        let response = http
+            .post_answer(PuzzleId::new(2025, day!(7)), 2, "42")
+            .unwrap();
+        assert!(response.starts_with("That's the right answer!"));
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn requires_session() {
+        let http = Http::new("http://127.0.0.1:1", None);
+        assert!(http.input(PuzzleId::new(2025, day!(1))).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+/// Provider that works entirely offline, serving puzzles from fixture files.
+/// The fixture directory (`AOC_FIXTURES`, defaults to `data/fixtures`) mirrors the layout of `data`:
+///  - `{year}/inputs/{day}.txt` and `{year}/puzzles/{day}.md` are copied by `download`.
+///  - `{year}/answers/{day}.txt` holds the expected answers in the format of
+///    [`crate::template::answers`].
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use super::{get_input_path, get_puzzle_path, Provider, ProviderError};
+use crate::template::answers::Answers;
+use crate::template::submission::SubmissionResult;
+use crate::PuzzleId;
+
+pub struct Local {
+    root: PathBuf,
+}
+
+impl Local {
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::new(
+            env::var("AOC_FIXTURES").unwrap_or_else(|_| "data/fixtures".into()),
+        )
+    }
+
+    fn fixture_path(&self, puzzle: PuzzleId, kind: &str, ext: &str) -> PathBuf {
+        self.root
+            .join(puzzle.year.to_string())
+            .join(kind)
+            .join(format!("{}.{ext}", puzzle.day))
+    }
+
+    fn read_fixture(path: &Path) -> Result<String, ProviderError> {
+        fs::read_to_string(path).map_err(|_| {
+            ProviderError::MissingFixture(path.display().to_string())
+        })
+    }
+}
+
+impl Provider for Local {
+    fn check(&self) -> Result<(), ProviderError> {
+        if self.root.is_dir() {
+            Ok(())
+        } else {
+            Err(ProviderError::MissingFixture(
+                self.root.display().to_string(),
+            ))
+        }
+    }
+
+    fn download(&self, puzzle: PuzzleId) -> Result<(), ProviderError> {
+        let input =
+            Self::read_fixture(&self.fixture_path(puzzle, "inputs", "txt"))?;
+        let description =
+            Self::read_fixture(&self.fixture_path(puzzle, "puzzles", "md"))?;
+
+        let input_path = get_input_path(puzzle);
+        let puzzle_path = get_puzzle_path(puzzle);
+        fs::write(&input_path, input)?;
+        fs::write(&puzzle_path, description)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn read(&self, puzzle: PuzzleId) -> Result<(), ProviderError> {
+        let path = self.fixture_path(puzzle, "puzzles", "md");
+        println!("{}", Self::read_fixture(&path)?);
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResult, ProviderError> {
+        let path = self.fixture_path(puzzle, "answers", "txt");
+        let answers = Answers::parse(&Self::read_fixture(&path)?);
+
+        let Some(expected) = answers.get(part) else {
+            return Err(ProviderError::MissingFixture(format!(
+                "{} (part {part})",
+                path.display()
+            )));
+        };
+
+        Ok(judge(expected, answer))
+    }
+}
+
+/// Judges an answer like the site does, with hints for numbers.
+fn judge(expected: &str, answer: &str) -> SubmissionResult {
+    if answer == expected {
+        return SubmissionResult::Correct;
+    }
+
+    match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => {
+            SubmissionResult::TooHigh
+        }
+        (Ok(answer), Ok(expected)) if answer < expected => {
+            SubmissionResult::TooLow
+        }
+        _ => SubmissionResult::Wrong,
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{judge, Local};
+    use crate::template::provider::{Provider, ProviderError};
+    use crate::template::submission::SubmissionResult;
+    use crate::{day, PuzzleId};
+    use std::{env, fs};
+
+    #[test]
+    fn judges_answers() {
+        assert_eq!(judge("42", "42"), SubmissionResult::Correct);
+        assert_eq!(judge("42", "43"), SubmissionResult::TooHigh);
+        assert_eq!(judge("42", "41"), SubmissionResult::TooLow);
+        assert_eq!(judge("ABC", "ABD"), SubmissionResult::Wrong);
+    }
+
+    #[test]
+    fn submits_against_fixtures() {
+        let root = env::temp_dir()
+            .join(format!("aoc-fixtures-{}", std::process::id()));
+        fs::create_dir_all(root.join("2025/answers")).unwrap();
+        fs::write(root.join("2025/answers/01.txt"), "1: 42\n").unwrap();
+
+        let local = Local::new(&root);
+        assert!(local.check().is_ok());
+        assert_eq!(
+            local.submit(PuzzleId::new(2025, day!(1)), 1, "42").unwrap(),
+            SubmissionResult::Correct
+        );
+        assert_eq!(
+            local.submit(PuzzleId::new(2025, day!(1)), 1, "7").unwrap(),
+            SubmissionResult::TooLow
+        );
+        assert!(matches!(
+            local.submit(PuzzleId::new(2025, day!(1)), 2, "42"),
+            Err(ProviderError::MissingFixture(_))
+        ));
+        assert!(matches!(
+            local.submit(PuzzleId::new(2025, day!(2)), 1, "42"),
+            Err(ProviderError::MissingFixture(_))
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+/// Module that abstracts over where puzzles come from and where answers are submitted to.
+/// The provider is picked with the `AOC_PROVIDER` environment variable:
+///  - `http` (default): talks to the website directly, see [`http::Http`].
+///  - `aoc-cli`: shells out to the `aoc` command-line tool.
+///  - `local`: serves puzzles and judges answers from fixture files, see [`local::Local`].
+use std::{env, fmt::Display, io};
+
+use crate::template::aoc_cli::{AocCli, AocCommandError};
+use crate::template::config;
+use crate::template::submission::SubmissionResult;
+use crate::PuzzleId;
+
+pub mod http;
+pub mod local;
+
+pub trait Provider {
+    /// Checks that the provider can be used, e.g. that required tools are installed.
+    fn check(&self) -> Result<(), ProviderError>;
+
+    /// Writes the input to `data/{year}/inputs/{day}.txt` and the description to
+    /// `data/{year}/puzzles/{day}.md`.
+    fn download(&self, puzzle: PuzzleId) -> Result<(), ProviderError>;
+
+    /// Prints the description of a puzzle.
+    fn read(&self, puzzle: PuzzleId) -> Result<(), ProviderError>;
+
+    /// Submits an answer and returns how it was judged.
+    fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionResult, ProviderError>;
+}
+
+#[derive(Debug)]
+pub enum ProviderError {
+    Unknown(String),
+    Config(String),
+    Http(String),
+    AocCli(AocCommandError),
+    MissingFixture(String),
+    UnexpectedResponse(String),
+    Io(io::Error),
+}
+
+impl Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProviderError::Unknown(name) => write!(
+                f,
+                "unknown provider \"{name}\", expected \"http\", \"aoc-cli\" or \"local\"."
+            ),
+            ProviderError::Config(message) => write!(f, "{message}"),
+            ProviderError::Http(message) => write!(f, "request failed: {message}"),
+            ProviderError::AocCli(AocCommandError::CommandNotFound) => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            ProviderError::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+            ProviderError::MissingFixture(path) => write!(f, "missing fixture \"{path}\"."),
+            ProviderError::UnexpectedResponse(response) => {
+                write!(f, "unexpected response: {response}")
+            }
+            ProviderError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<AocCommandError> for ProviderError {
+    fn from(e: AocCommandError) -> Self {
+        ProviderError::AocCli(e)
+    }
+}
+
+impl From<io::Error> for ProviderError {
+    fn from(e: io::Error) -> Self {
+        ProviderError::Io(e)
+    }
+}
+
+/// Returns the provider selected by `AOC_PROVIDER`.
+pub fn get() -> Result<Box<dyn Provider>, ProviderError> {
+    match env::var("AOC_PROVIDER").as_deref() {
+        Err(_) | Ok("http") => Ok(Box::new(http::Http::from_env())),
+        Ok("aoc-cli") => Ok(Box::new(AocCli)),
+        Ok("local") => Ok(Box::new(local::Local::from_env())),
+        Ok(name) => Err(ProviderError::Unknown(name.to_string())),
+    }
+}
+
+/// Returns the selected provider after checking that it can be used.
+pub fn get_checked() -> Result<Box<dyn Provider>, ProviderError> {
+    let provider = get()?;
+    provider.check()?;
+    Ok(provider)
+}
+
+/// Parses the response message of a submission.
+pub fn parse_response(
+    response: &str,
+) -> Result<SubmissionResult, ProviderError> {
+    SubmissionResult::parse(response).ok_or_else(|| {
+        ProviderError::UnexpectedResponse(response.trim().to_string())
+    })
+}
+
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    let path = config::get()
+        .paths
+        .folder(puzzle.year, "inputs")
+        .join(format!("{}.txt", puzzle.day));
+    path.display().to_string()
+}
+
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    let path = config::get()
+        .paths
+        .folder(puzzle.year, "puzzles")
+        .join(format!("{}.md", puzzle.day));
+    path.display().to_string()
+}
@@ -2,9 +2,13 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::config;
+use crate::template::memory::format_bytes;
+use crate::PuzzleId;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+fn marker() -> &'static str {
+    &config::get().readme.marker
+}
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,10 +24,12 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Peak heap usage of the day's parts, only measured with the `memory` feature.
+    pub peak_memory: Option<usize>,
 }
 
 pub struct TablePosition {
@@ -32,12 +38,13 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    let bin = &config::get().paths.bin;
+    format!("./{}/{}/{}.rs", bin.display(), puzzle.year, puzzle.day)
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+    let matches: Vec<_> = readme.match_indices(marker()).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -60,29 +67,40 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 
 fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
+    let has_memory = timings.iter().any(|t| t.peak_memory.is_some());
+
+    let mut lines: Vec<String> = vec![marker().into(), header, String::new()];
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    if has_memory {
+        lines.push("| Day | Part 1 | Part 2 | Peak memory |".into());
+        lines.push("| :---: | :---: | :---:  | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = get_path_for_bin(timing.puzzle);
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            timing.puzzle.day.into_inner(),
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        );
+
+        if has_memory {
+            let memory =
+                timing.peak_memory.map_or_else(|| "-".into(), format_bytes);
+            line.push_str(&format!(" `{memory}` |"));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker().into());
 
     lines.join("\n")
 }
@@ -95,37 +113,40 @@ fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> R
 }
 
 pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
+    let path = &config::get().readme.path;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     update_content(&mut readme, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{marker, update_content, Timings};
+    use crate::{day, PuzzleId};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: PuzzleId::new(2025, day!(1)),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
+                peak_memory: None,
             },
             Timings {
-                day: day!(2),
+                puzzle: PuzzleId::new(2025, day!(2)),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
+                peak_memory: None,
             },
             Timings {
-                day: day!(4),
+                puzzle: PuzzleId::new(2025, day!(4)),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
+                peak_memory: None,
             },
         ]
     }
@@ -140,29 +161,29 @@ mod tests {
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
+        let mut s = format!("{} {} {}", marker(), marker(), marker());
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(), marker());
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(), marker());
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches(marker()).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(), marker());
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
@@ -172,9 +193,37 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025/04.rs) | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings[0].peak_memory = Some(1536);
+        timings[1].peak_memory = Some(3 * 1024 * 1024);
+
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(), marker());
+        update_content(&mut s, timings, 190.0).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 | Peak memory |",
+            "| :---: | :---: | :---:  | :---: |",
+            "| [Day 1](./src/bin/2025/01.rs) | `10ms` | `20ms` | `1.5 KiB` |",
+            "| [Day 2](./src/bin/2025/02.rs) | `30ms` | `40ms` | `3.0 MiB` |",
+            "| [Day 4](./src/bin/2025/04.rs) | `40ms` | `50ms` | `-` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
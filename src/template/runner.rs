@@ -1,73 +1,690 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::memory::{self, MemoryStats};
+use crate::template::submission::{self, SubmissionResult};
+use crate::template::{
+    answers, config, context::Context, cooldown, provider, ANSI_ITALIC,
+    ANSI_RESET,
+};
+use crate::{Answer, PuzzleId};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::{mpsc, Arc};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(
-    func: impl Fn(I) -> Option<T>,
-    input: I,
-    day: Day,
+/// A solution part: either `fn(&I) -> Option<T>` or `fn(&I, &Context) -> Option<T>`, where `I` is the
+/// input, `str` unless the day has a parse function (see [`run_parse`]).
+/// `Args` only tells the two forms apart and is inferred at the call site.
+pub trait Part<Args, T, I: ?Sized = str>: Send + Sync + 'static {
+    fn solve(&self, input: &I, context: &Context) -> Option<T>;
+}
+
+impl<F, T, I: ?Sized> Part<fn(&I), T, I> for F
+where
+    F: Fn(&I) -> Option<T> + Send + Sync + 'static,
+{
+    fn solve(&self, input: &I, _: &Context) -> Option<T> {
+        self(input)
+    }
+}
+
+impl<F, T, I: ?Sized> Part<fn(&I, &Context), T, I> for F
+where
+    F: Fn(&I, &Context) -> Option<T> + Send + Sync + 'static,
+{
+    fn solve(&self, input: &I, context: &Context) -> Option<T> {
+        self(input, context)
+    }
+}
+
+/// The part number of the records of a day's parse function.
+pub const PARSE: u8 = 0;
+
+/// The name of a part in the output, e.g. `Part 1` or `Parse`.
+#[must_use]
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+pub fn run_part<A, T, F, I>(
+    func: F,
+    input: &'static I,
+    context: &Arc<Context>,
+    puzzle: PuzzleId,
     part: u8,
-) {
-    let part_str = format!("Part {part}");
+) where
+    F: Part<A, T, I>,
+    T: Into<Answer>,
+    I: ?Sized + Sync,
+{
+    let format = OutputFormat::from_args();
+    let part_str = part_label(part);
+
+    let outcome =
+        run_timed(with_context(func, context), input, timeout(), |result| {
+            if format == OutputFormat::Text {
+                print_result(result, &part_str, "");
+
+                if is_timed() {
+                    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                    let _ = stdout().flush();
+                }
+            }
+        });
+
+    let record = PartRecord::from_outcome(puzzle, part, &outcome);
+
+    match format {
+        OutputFormat::Text => print_record(&record),
+        OutputFormat::Json => println!("{}", record.to_json()),
+    }
+
+    if let Ok(Measured {
+        result: Some(result),
+        ..
+    }) = outcome
+    {
+        submit_result(result, puzzle, part);
+    }
+}
+
+/// Runs the parse function of a day and prints its record like [`run_part`], timed separately from the parts.
+/// Returns the parsed input, leaked to share it with the threads of the parts, or `None` if parsing failed.
+pub fn run_parse<P, F>(
+    func: F,
+    input: &'static str,
+    puzzle: PuzzleId,
+) -> Option<&'static P>
+where
+    F: Fn(&'static str) -> P + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+{
+    let format = OutputFormat::from_args();
+
+    let (record, parsed) = parse_timed(func, input, puzzle, |_| {
+        if format == OutputFormat::Text && is_timed() {
+            print!(
+                "{}: > {ANSI_ITALIC}benching{ANSI_RESET}",
+                part_label(PARSE)
+            );
+            let _ = stdout().flush();
+        }
+    });
+
+    match format {
+        OutputFormat::Text => print_record(&record),
+        OutputFormat::Json => println!("{}", record.to_json()),
+    }
+
+    parsed
+}
+
+/// Run a parse function without printing anything and return its record and the parsed input.
+/// Used when several days run in the same process.
+pub fn solve_parse<P, F>(
+    func: F,
+    input: &'static str,
+    puzzle: PuzzleId,
+) -> (PartRecord, Option<&'static P>)
+where
+    F: Fn(&'static str) -> P + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+{
+    parse_timed(func, input, puzzle, |_| {})
+}
+
+fn parse_timed<P, F>(
+    func: F,
+    input: &'static str,
+    puzzle: PuzzleId,
+    hook: impl Fn(&P),
+) -> (PartRecord, Option<&'static P>)
+where
+    F: Fn(&'static str) -> P + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+{
+    match run_timed(func, input, timeout(), hook) {
+        Ok(measured) => {
+            let record = PartRecord {
+                memory: measured.memory,
+                ..PartRecord::parsed(puzzle, measured.stats)
+            };
+            let parsed: &'static P = Box::leak(Box::new(measured.result));
+            (record, Some(parsed))
+        }
+        Err(failure) => (PartRecord::failed(puzzle, PARSE, &failure), None),
+    }
+}
+
+/// Run a solution part without printing anything and return its record.
+/// Used when several days run in the same process.
+pub fn solve_part<A, T, F, I>(
+    func: F,
+    input: &'static I,
+    context: &Arc<Context>,
+    puzzle: PuzzleId,
+    part: u8,
+) -> PartRecord
+where
+    F: Part<A, T, I>,
+    T: Into<Answer>,
+    I: ?Sized + Sync,
+{
+    let outcome =
+        run_timed(with_context(func, context), input, timeout(), |_| {});
+    PartRecord::from_outcome(puzzle, part, &outcome)
+}
+
+fn with_context<A, T: Into<Answer>, F: Part<A, T, I>, I: ?Sized + Sync>(
+    func: F,
+    context: &Arc<Context>,
+) -> impl Fn(&'static I) -> Option<Answer> + Send + Sync + 'static {
+    let context = Arc::clone(context);
+    move |input| func.solve(input, &context).map(Into::into)
+}
+
+/// A day registered by the `solution!` macro, runnable in-process by the `aoc-all` binary.
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: fn() -> Vec<PartRecord>,
+}
+
+/// Solutions run on a separate thread, give it as much stack as the main thread usually gets.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn is_timed() -> bool {
+    env::args().any(|x| x == "--time")
+}
+
+/// Reads the `--part` argument passed to a solution binary and tells whether `part` runs.
+/// Both parts run unless one is selected.
+#[must_use]
+pub fn is_selected(part: u8) -> bool {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--part") else {
+        return true;
+    };
+
+    match args.get(index + 1).map(|x| x.parse::<u8>()) {
+        Some(Ok(selected @ 1..=2)) => selected == part,
+        _ => {
+            eprintln!(
+                "Unexpected command-line input. Format: cargo solve 1 --part 2"
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the `--timeout` argument (in seconds) passed to a solution binary.
+/// Defaults to the wall-clock limit in the config.
+fn timeout() -> Duration {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--timeout") else {
+        return config::get().bench.timeout();
+    };
+
+    match args.get(index + 1).map(|x| x.parse()) {
+        Some(Ok(secs)) if secs > 0 => Duration::from_secs(secs),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --timeout 60");
+            process::exit(1);
+        }
+    }
+}
+
+/// The result of a part together with its measurements.
+struct Measured<T> {
+    result: T,
+    stats: BenchStats,
+    memory: Option<MemoryStats>,
+}
+
+/// Why a part did not produce a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {timeout:?}")
+            }
+        }
+    }
+}
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable, ANSI-decorated lines.
+    #[default]
+    Text,
+    /// One JSON [`PartRecord`] per line.
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the `--format` argument passed to a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let Some(index) = args.iter().position(|x| x == "--format") else {
+            return Self::Text;
+        };
+
+        match args.get(index + 1).map(|x| x.parse()) {
+            Some(Ok(format)) => format,
+            _ => {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --format json");
+                process::exit(1);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            x => Err(format!(
+                "unknown output format `{x}`, expecting `text` or `json`"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    Panicked,
+    TimedOut,
+}
+
+/// The machine-readable result of running one solution part.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartRecord {
+    #[serde(flatten)]
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// Describes the failure of a panicked or timed out part.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub stats: BenchStats,
+    /// Heap usage of the first execution, only measured with the `memory` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartRecord {
+    #[must_use]
+    pub fn new<T: Display>(
+        puzzle: PuzzleId,
+        part: u8,
+        result: Option<&T>,
+        stats: BenchStats,
+    ) -> Self {
+        Self {
+            puzzle,
+            part,
+            status: if result.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: result.map(ToString::to_string),
+            message: None,
+            stats,
+            memory: None,
+        }
+    }
+
+    /// The record of a parse function that returned, which has no answer.
+    #[must_use]
+    pub fn parsed(puzzle: PuzzleId, stats: BenchStats) -> Self {
+        Self {
+            status: PartStatus::Solved,
+            ..Self::new::<String>(puzzle, PARSE, None, stats)
+        }
+    }
+
+    /// The name of the part in the output, e.g. `Part 1` or `Parse`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        part_label(self.part)
+    }
+
+    #[must_use]
+    pub fn failed(puzzle: PuzzleId, part: u8, failure: &Failure) -> Self {
+        let (status, duration) = match failure {
+            Failure::Panicked(_) => (PartStatus::Panicked, Duration::ZERO),
+            Failure::TimedOut(timeout) => (PartStatus::TimedOut, *timeout),
+        };
+
+        Self {
+            puzzle,
+            part,
+            status,
+            answer: None,
+            message: Some(failure.to_string()),
+            stats: BenchStats::single(duration),
+            memory: None,
+        }
+    }
+
+    fn from_outcome<T: Display>(
+        puzzle: PuzzleId,
+        part: u8,
+        outcome: &Result<Measured<Option<T>>, Failure>,
+    ) -> Self {
+        match outcome {
+            Ok(measured) => Self {
+                memory: measured.memory,
+                ..Self::new(
+                    puzzle,
+                    part,
+                    measured.result.as_ref(),
+                    measured.stats.clone(),
+                )
+            },
+            Err(failure) => Self::failed(puzzle, part, failure),
+        }
+    }
+
+    /// # Panics
+    /// Never, a record always serializes.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Parses a line printed by a solution binary in [`OutputFormat::Json`] mode.
+    #[must_use]
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line).ok()
+    }
+}
+
+/// Prints a record the same way a solution binary does in [`OutputFormat::Text`] mode.
+pub fn print_record(record: &PartRecord) {
+    let part = record.label();
+
+    match &record.message {
+        Some(message) => {
+            print!("\r");
+            println!("{part}: ✖ {message}");
+        }
+        None => {
+            let mut details = format_duration(&record.stats);
+
+            if let Some(memory) = &record.memory {
+                details.push_str(&format!(
+                    " [{} peak, {} allocations]",
+                    memory.summary(),
+                    memory.allocations
+                ));
+            }
+
+            if record.part == PARSE {
+                print!("\r");
+                println!("{part}:{details}");
+            } else {
+                print_result(
+                    &record.answer.as_deref().map(Answer::from),
+                    &part,
+                    &details,
+                );
+            }
+        }
+    }
+}
+
+/// Timing statistics of a solution part.
+/// A single (non-benched) run is reported as one sample with all statistics set to its duration.
+/// Durations are serialized as integer nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub samples: u128,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Duration,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            min: duration,
+            median: duration,
+            p95: duration,
+            mean: duration,
+            std_dev: Duration::ZERO,
+            outliers: 0,
+        }
+    }
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    /// Computes the statistics of a non-empty list of samples.
+    /// Outliers are samples outside of the Tukey fences (1.5 times the interquartile range below the
+    /// first or above the third quartile).
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u128> =
+            samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let count = nanos.len();
+        let mean = nanos.iter().sum::<u128>() as f64 / count as f64;
+        let variance = nanos
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let q1 = percentile(&nanos, 25) as f64;
+        let q3 = percentile(&nanos, 75) as f64;
+        let fence = (q3 - q1) * 1.5;
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| (x as f64) < q1 - fence || (x as f64) > q3 + fence)
+            .count();
+
+        Self {
+            samples: count as u128,
+            min: duration_from_nanos(nanos[0]),
+            median: duration_from_nanos(percentile(&nanos, 50)),
+            p95: duration_from_nanos(percentile(&nanos, 95)),
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers,
+        }
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    /// Whether these statistics come from benching rather than a single run.
+    #[must_use]
+    pub fn is_benched(&self) -> bool {
+        self.samples > 1
+    }
+
+    /// The median and its spread, e.g. `12.3µs ± 0.4µs`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!("{:.1?} ± {:.1?}", self.median, self.std_dev)
+    }
+}
+
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// Nearest-rank percentile of an ascending list of values.
+fn percentile(sorted: &[u128], p: usize) -> u128 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn duration_from_nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(nanos as u64)
+}
+
+/// Run a solution part on its own thread, catching panics and giving up after `timeout`.
+/// A part that times out keeps running in the background until the process exits.
+fn run_isolated<I, T, F>(
+    func: &Arc<F>,
+    input: I,
+    timeout: Duration,
+) -> Result<(T, Duration, Option<MemoryStats>), Failure>
+where
+    F: Fn(I) -> T + Send + Sync + 'static,
+    I: Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let func = Arc::clone(func);
+
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let measurement = memory::start();
+            let timer = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+            let elapsed = timer.elapsed();
+            let memory = measurement.map(memory::Measurement::finish);
+            let _ = sender.send(
+                result
+                    .map(|result| (result, elapsed, memory))
+                    .map_err(panic_message),
+            );
+        })
+        .expect("could not spawn solution thread");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(message)) => Err(Failure::Panicked(message)),
+        Err(_) => Err(Failure::TimedOut(timeout)),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => (*message).to_string(),
+            Err(_) => "unknown panic payload".into(),
+        },
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.
+///     Both limits can be changed in the `[bench]` table of the config.)
+///
+/// Only the first execution is isolated, a part that finishes within the timeout once is benched as is.
+fn run_timed<I, T, F>(
+    func: F,
     input: I,
+    timeout: Duration,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> Result<Measured<T>, Failure>
+where
+    F: Fn(I) -> T + Send + Sync + 'static,
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    let func = Arc::new(func);
+    let (result, base_time, memory) =
+        run_isolated(&func, input.clone(), timeout)?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if is_timed() {
+        bench(&*func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    Ok(Measured {
+        result,
+        stats,
+        memory,
+    })
 }
 
 fn bench<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     base_time: &Duration,
-) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+) -> BenchStats {
+    let limits = &config::get().bench;
+    let bench_iterations = (limits.target().as_nanos()
+        / cmp::max(base_time.as_nanos(), 10))
+    .clamp(limits.min_samples.into(), limits.max_samples.into());
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos()
-                / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    // warm up caches and the branch predictor before sampling.
+    for _ in 0..cmp::max(bench_iterations / 10, 1) {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -79,40 +696,36 @@ fn bench<I: Clone, T>(
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    BenchStats::from_samples(&timers)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.is_benched() {
+        format!(
+            " ({}, min {:.1?}, p95 {:.1?}, {} outliers @ {} samples)",
+            stats.summary(),
+            stats.min,
+            stats.p95,
+            stats.outliers,
+            stats.samples
+        )
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({:.1?})", stats.median)
     }
 }
 
-fn print_result<T: Display>(
-    result: &Option<T>,
-    part: &str,
-    duration_str: &str,
-) {
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+            if result.is_grid() {
+                let str = match result.letters() {
+                    Some(letters) => {
+                        format!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET} ▼{duration_str}")
+                    }
+                    None => format!("{part}: ▼ {duration_str}"),
+                };
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -145,12 +758,14 @@ fn print_result<T: Display>(
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+///  2. the configured provider can be used.
+///  3. the answer is not ruled out by previous guesses, unless the guard is disabled in the config.
+///  4. the part is not cooling down from a rate-limited submission, unless `--wait` is passed or configured.
+fn submit_result(
+    result: Answer,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmissionResult, provider::ProviderError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -177,11 +792,252 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let result = result.recognized();
+    if result.is_grid() {
+        eprintln!("Not submitting ASCII art that draws no known letters, submit the letters instead.");
+        return None;
+    }
+
+    let guesses = submission::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {e}", submission::get_path(puzzle));
+        submission::GuessLog::default()
+    });
+
+    let behavior = &config::get().submit;
+
+    if let Some(refusal) =
+        guesses.refusal(part, &result).filter(|_| behavior.guard)
+    {
+        eprintln!("Not submitting {result}: {refusal}.");
+        return None;
+    }
+
+    let is_waiting = behavior.wait || args.contains(&"--wait".into());
+    let result = result.to_string();
+
+    let provider = match provider::get_checked() {
+        Ok(provider) => provider,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Some(remaining) = remaining_cooldown(puzzle, part) {
+        if !is_waiting {
+            eprintln!(
+                "Not submitting {result}: rate limited for another {}. Append `--wait` to wait for it.",
+                cooldown::format_wait(remaining)
+            );
+            return None;
+        }
+
+        wait_for_cooldown(remaining);
+    }
+
+    loop {
+        eprintln!("Submitting result...");
+        let output = provider.submit(puzzle, part, &result);
+
+        match &output {
+            Ok(submission) => {
+                eprintln!("Submission: {submission}");
+                record_submission(puzzle, part, &result, submission);
+            }
+            Err(e) => eprintln!("Failed to submit: {e}"),
+        }
+
+        // wait for the cooldown the site reported, which is never zero, so that a cooldown
+        // that failed to be recorded cannot cause a resubmission right away.
+        match output {
+            Ok(SubmissionResult::RateLimited { wait }) if is_waiting => {
+                wait_for_cooldown(wait);
+            }
+            output => return Some(output),
+        }
+    }
+}
+
+fn remaining_cooldown(puzzle: PuzzleId, part: u8) -> Option<Duration> {
+    match cooldown::read() {
+        Ok(cooldowns) => cooldowns.remaining(puzzle, part, cooldown::now()),
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\": {e}",
+                cooldown::get_path().display()
+            );
+            None
+        }
+    }
+}
+
+/// Blocks until `remaining` passed, printing a countdown.
+fn wait_for_cooldown(remaining: Duration) {
+    let deadline = Instant::now() + remaining;
+
+    while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+        let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        eprint!(
+            "\rWaiting {} for the cooldown...   ",
+            cooldown::format_wait(Duration::from_secs(secs))
+        );
+        thread::sleep(left.min(Duration::from_secs(1)));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    eprintln!();
+}
+
+/// Remembers a cooldown, a judged guess and, if it was correct, the accepted answer.
+fn record_submission(
+    puzzle: PuzzleId,
+    part: u8,
+    answer: &str,
+    submission: &SubmissionResult,
+) {
+    if let SubmissionResult::RateLimited { wait } = submission {
+        if let Err(e) = cooldown::record(puzzle, part, *wait) {
+            eprintln!("Failed to record cooldown: {e}");
+        }
+    }
+
+    if submission.is_judged() {
+        if let Err(e) = submission::record(puzzle, part, answer, submission) {
+            eprintln!("Failed to record guess: {e}");
+        }
+    }
+
+    if *submission == SubmissionResult::Correct {
+        match answers::record(puzzle, part, answer) {
+            Ok(()) => eprintln!(
+                "Recorded answer in \"{}\".",
+                answers::get_path(puzzle)
+            ),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{
+        format_duration, run_isolated, BenchStats, Failure, Part, PartRecord,
+        PartStatus,
+    };
+    use crate::template::context::{Context, InputKind};
+    use crate::{day, PuzzleId};
+    use std::{sync::Arc, thread, time::Duration};
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn stats_of_uniform_samples() {
+        let stats = BenchStats::from_samples(&micros(&[10; 20]));
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(10));
+        assert_eq!(stats.p95, Duration::from_micros(10));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn stats_detect_outliers() {
+        let mut samples = vec![10, 11, 12, 10, 11, 12, 10, 11, 12, 11];
+        samples.push(500);
+        let stats = BenchStats::from_samples(&micros(&samples));
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(11));
+        assert_eq!(stats.p95, Duration::from_micros(500));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.mean > stats.median);
+    }
+
+    #[test]
+    fn format_single_run() {
+        let stats = BenchStats::single(Duration::from_micros(42));
+        assert_eq!(format_duration(&stats), " (42.0µs)");
+    }
+
+    #[test]
+    fn format_bench_stats() {
+        let stats = BenchStats::from_samples(&micros(&[10, 20, 30]));
+        assert_eq!(
+            format_duration(&stats),
+            " (20.0µs ± 8.2µs, min 10.0µs, p95 30.0µs, 0 outliers @ 3 samples)"
+        );
+    }
+
+    #[test]
+    fn isolated_run_returns_result() {
+        let func = Arc::new(|x: u32| x * 2);
+        let (result, _, _) =
+            run_isolated(&func, 21, Duration::from_secs(5)).unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn isolated_run_catches_panics() {
+        let func = Arc::new(|_: ()| -> u32 { panic!("boom") });
+        let failure =
+            run_isolated(&func, (), Duration::from_secs(5)).unwrap_err();
+        assert_eq!(failure, Failure::Panicked("boom".into()));
+        assert_eq!(failure.to_string(), "panicked: boom");
+    }
+
+    #[test]
+    fn isolated_run_times_out() {
+        let func = Arc::new(|_: ()| thread::sleep(Duration::from_secs(5)));
+        let failure =
+            run_isolated(&func, (), Duration::from_millis(10)).unwrap_err();
+        assert_eq!(failure, Failure::TimedOut(Duration::from_millis(10)));
+        assert_eq!(failure.to_string(), "timed out after 10ms");
+    }
+
+    #[test]
+    fn failed_record_round_trip() {
+        let record = PartRecord::failed(
+            PuzzleId::new(2025, day!(3)),
+            2,
+            &Failure::TimedOut(Duration::from_secs(60)),
+        );
+        assert_eq!(record.status, PartStatus::TimedOut);
+        assert_eq!(record.message.as_deref(), Some("timed out after 60s"));
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn parse_record_round_trip() {
+        let record = PartRecord::parsed(
+            PuzzleId::new(2025, day!(8)),
+            BenchStats::single(Duration::from_micros(5)),
+        );
+        assert_eq!(record.status, PartStatus::Solved);
+        assert_eq!(record.answer, None);
+        assert_eq!(record.label(), "Parse");
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    // parts take a reference to whatever the parse function returns.
+    #[allow(clippy::ptr_arg)]
+    fn parts_take_parsed_input() {
+        fn part_one(values: &Vec<u32>) -> Option<u32> {
+            Some(values.iter().sum())
+        }
+
+        fn part_two(values: &Vec<u32>, context: &Context) -> Option<u32> {
+            values.get(context.param::<usize>("index")).copied()
+        }
+
+        let context = Context::new(
+            PuzzleId::new(2025, day!(1)),
+            InputKind::Example,
+            toml::from_str("index = 1").unwrap(),
+        );
+
+        assert_eq!(part_one.solve(&vec![1, 2], &context), Some(3));
+        assert_eq!(part_two.solve(&vec![1, 2], &context), Some(2));
+    }
 }
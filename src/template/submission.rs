@@ -0,0 +1,350 @@
+/// Module that interprets submission responses and keeps a log of every guess per day.
+/// Guesses live in `data/{year}/guesses/{day}.jsonl`, one JSON line per judged submission, and are used to
+/// refuse answers that cannot be right before they cost a lockout.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::OnceLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_dir;
+use crate::{Answer, PuzzleId};
+
+/// The wait assumed when the site rate-limits without a readable remaining time.
+pub const UNKNOWN_WAIT: Duration = Duration::from_secs(60);
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+
+impl SubmissionResult {
+    /// Parses the response message of the site. Returns `None` if the message is not recognized.
+    #[must_use]
+    pub fn parse(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Self::Wrong)
+        } else if response.contains("You gave an answer too recently") {
+            // never wait for nothing, that would resubmit right away.
+            let wait = parse_wait(response)
+                .map_or(UNKNOWN_WAIT, |wait| wait.max(Duration::from_secs(1)));
+            Some(Self::RateLimited { wait })
+        } else if response
+            .contains("You don't seem to be solving the right level")
+        {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the site judged the answer, i.e. the guess is worth remembering.
+    #[must_use]
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Self::RateLimited { .. } | Self::AlreadySolved)
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "✔ correct"),
+            Self::TooHigh => write!(f, "✖ too high"),
+            Self::TooLow => write!(f, "✖ too low"),
+            Self::Wrong => write!(f, "✖ wrong"),
+            Self::RateLimited { wait } => {
+                write!(f, "⏳ rate limited, wait {}s", wait.as_secs())
+            }
+            Self::AlreadySolved => write!(f, "✔ already solved"),
+        }
+    }
+}
+
+/// Parses the remaining time of a response like "You have 1m 5s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+
+    let re = RE.get_or_init(|| {
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap()
+    });
+    let captures = re.captures(response)?;
+
+    let minutes: u64 =
+        captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = captures[2].parse().ok()?;
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub result: SubmissionResult,
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyGuessed(SubmissionResult),
+    NotBelow(String),
+    NotAbove(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyGuessed(result) => {
+                write!(f, "it was submitted before ({result})")
+            }
+            Refusal::NotBelow(bound) => {
+                write!(f, "{bound} was already too high")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "{bound} was already too low")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    fn parse(s: &str) -> Self {
+        Self {
+            guesses: s
+                .lines()
+                .filter_map(|l| serde_json::from_str(l).ok())
+                .collect(),
+        }
+    }
+
+    /// Checks an answer against the previous guesses of a part.
+    #[must_use]
+    pub fn refusal(&self, part: u8, answer: &Answer) -> Option<Refusal> {
+        let guesses = || self.guesses.iter().filter(move |g| g.part == part);
+
+        if let Some(guess) =
+            guesses().find(|g| Answer::from(g.answer.as_str()) == *answer)
+        {
+            return Some(Refusal::AlreadyGuessed(guess.result.clone()));
+        }
+
+        let Answer::Integer(answer) = *answer else {
+            return None;
+        };
+        let bound = |result: SubmissionResult| {
+            guesses()
+                .filter(move |g| g.result == result)
+                .filter_map(|g| match Answer::from(g.answer.as_str()) {
+                    Answer::Integer(n) => Some(n),
+                    _ => None,
+                })
+        };
+
+        if let Some(high) = bound(SubmissionResult::TooHigh)
+            .filter(|&b| answer >= b)
+            .min()
+        {
+            return Some(Refusal::NotBelow(high.to_string()));
+        }
+
+        if let Some(low) = bound(SubmissionResult::TooLow)
+            .filter(|&b| answer <= b)
+            .max()
+        {
+            return Some(Refusal::NotAbove(low.to_string()));
+        }
+
+        None
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    let path = get_data_dir(puzzle.year)
+        .join("guesses")
+        .join(format!("{}.jsonl", puzzle.day));
+    path.display().to_string()
+}
+
+/// Reads the guesses of a day. A missing file means nothing was submitted yet.
+pub fn read(puzzle: PuzzleId) -> io::Result<GuessLog> {
+    match fs::read_to_string(get_path(puzzle)) {
+        Ok(s) => Ok(GuessLog::parse(&s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Ok(GuessLog::default())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Appends a judged submission to the guess log of a day.
+pub fn record(
+    puzzle: PuzzleId,
+    part: u8,
+    answer: &str,
+    result: &SubmissionResult,
+) -> io::Result<()> {
+    let guess = Guess {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        part,
+        answer: answer.to_string(),
+        result: result.clone(),
+    };
+
+    let path = get_path(puzzle);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(&guess).unwrap())
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Guess, GuessLog, Refusal, SubmissionResult, UNKNOWN_WAIT};
+    use crate::Answer;
+    use std::time::Duration;
+
+    fn log(guesses: &[(u8, &str, SubmissionResult)]) -> GuessLog {
+        GuessLog {
+            guesses: guesses
+                .iter()
+                .map(|(part, answer, result)| Guess {
+                    timestamp: 0,
+                    part: *part,
+                    answer: (*answer).to_string(),
+                    result: result.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", SubmissionResult::Correct),
+            ("That's not the right answer; your answer is too high.", SubmissionResult::TooHigh),
+            ("That's not the right answer; your answer is too low.", SubmissionResult::TooLow),
+            ("That's not the right answer. If you're stuck, ...", SubmissionResult::Wrong),
+            (
+                "You gave an answer too recently. You have 1m 5s left to wait.",
+                SubmissionResult::RateLimited { wait: Duration::from_secs(65) },
+            ),
+            (
+                "You gave an answer too recently. You have 30s left to wait.",
+                SubmissionResult::RateLimited { wait: Duration::from_secs(30) },
+            ),
+            (
+                "You gave an answer too recently. Please wait a bit.",
+                SubmissionResult::RateLimited { wait: UNKNOWN_WAIT },
+            ),
+            (
+                "You gave an answer too recently. You have 0s left to wait.",
+                SubmissionResult::RateLimited { wait: Duration::from_secs(1) },
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                SubmissionResult::AlreadySolved,
+            ),
+        ];
+
+        for (response, expected) in cases {
+            assert_eq!(SubmissionResult::parse(response), Some(expected));
+        }
+
+        assert_eq!(SubmissionResult::parse("Hello"), None);
+    }
+
+    #[test]
+    fn round_trips_guesses() {
+        let log = log(&[
+            (1, "42", SubmissionResult::TooHigh),
+            (2, "7", SubmissionResult::Correct),
+        ]);
+        let json = log
+            .guesses
+            .iter()
+            .map(|g| serde_json::to_string(g).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(json.starts_with(
+            r#"{"timestamp":0,"part":1,"answer":"42","result":"too_high"}"#
+        ));
+        assert_eq!(GuessLog::parse(&json), log);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = log(&[(1, "abc", SubmissionResult::Wrong)]);
+
+        assert_eq!(
+            log.refusal(1, &"abc".into()),
+            Some(Refusal::AlreadyGuessed(SubmissionResult::Wrong))
+        );
+        assert_eq!(log.refusal(2, &"abc".into()), None);
+        assert_eq!(log.refusal(1, &"abd".into()), None);
+    }
+
+    #[test]
+    fn compares_guesses_as_answers() {
+        let log = log(&[(1, "42", SubmissionResult::TooLow)]);
+
+        assert_eq!(
+            log.refusal(1, &Answer::from(42_u64)),
+            Some(Refusal::AlreadyGuessed(SubmissionResult::TooLow))
+        );
+        assert_eq!(
+            log.refusal(1, &Answer::from(" 40 ")),
+            Some(Refusal::NotAbove("42".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let log = log(&[
+            (1, "100", SubmissionResult::TooHigh),
+            (1, "80", SubmissionResult::TooHigh),
+            (1, "10", SubmissionResult::TooLow),
+        ]);
+
+        assert_eq!(
+            log.refusal(1, &"90".into()),
+            Some(Refusal::NotBelow("80".into()))
+        );
+        assert_eq!(
+            log.refusal(1, &"5".into()),
+            Some(Refusal::NotAbove("10".into()))
+        );
+        assert_eq!(log.refusal(1, &"50".into()), None);
+        assert_eq!(log.refusal(2, &"90".into()), None);
+    }
+}
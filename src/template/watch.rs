@@ -0,0 +1,85 @@
+/// Module that re-runs a command whenever one of the watched files changes.
+/// Files are polled for their modification time and size, which works on every platform without a
+/// native file notifier.
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The state of the watched files, `None` for missing ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<(SystemTime, u64)>)>);
+
+impl Snapshot {
+    #[must_use]
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Self(
+            paths
+                .iter()
+                .map(|path| {
+                    let state = fs::metadata(path)
+                        .ok()
+                        .and_then(|m| Some((m.modified().ok()?, m.len())));
+                    (path.clone(), state)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Clears the terminal and its scrollback.
+fn clear() {
+    print!("\x1B[2J\x1B[3J\x1B[H");
+    let _ = io::stdout().flush();
+}
+
+/// Calls `run` on a cleared terminal, then again after every change of the files returned by `paths`.
+/// `paths` is called on every poll so that new files are picked up. Runs until interrupted.
+pub fn watch(paths: impl Fn() -> Vec<PathBuf>, mut run: impl FnMut()) -> ! {
+    loop {
+        clear();
+        let before = Snapshot::take(&paths());
+        run();
+        println!("---");
+        println!("👀 Watching for changes, press Ctrl+C to stop.");
+
+        while Snapshot::take(&paths()) == before {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        // editors may save in several writes, wait for them to settle.
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Snapshot;
+    use std::{env, fs};
+
+    #[test]
+    fn detects_changes() {
+        let path = env::temp_dir()
+            .join(format!("aoc-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let paths = [path.clone()];
+
+        let missing = Snapshot::take(&paths);
+        assert_eq!(missing, Snapshot::take(&paths));
+
+        fs::write(&path, "1").unwrap();
+        let created = Snapshot::take(&paths);
+        assert_ne!(missing, created);
+
+        fs::write(&path, "12").unwrap();
+        assert_ne!(created, Snapshot::take(&paths));
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(missing, Snapshot::take(&paths));
+    }
+}